@@ -0,0 +1,667 @@
+// Loads and validates content/*.toml, then emits the Rust tables that
+// src/utils/ pulls in with include!.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::i18n::{self, Catalogs, Localized};
+use crate::json_ld;
+use crate::media::{self, MediaEntry};
+use crate::og_images;
+use crate::posts::{self, PostEntry};
+use crate::profile::{self, Profile};
+use crate::repo_stats::{self, StatsEntry};
+use crate::resume::{self, ResumeConfig};
+use crate::search;
+use crate::site::{self, SiteConfig};
+use crate::slug::slugify;
+
+/// Every media path in content/ is written relative to the site root like this.
+pub const ASSET_PREFIX: &str = "../assets/";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProjectsFile {
+    project: Vec<ProjectEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectEntry {
+    pub id: String,
+    pub name: Localized,
+    pub description: Localized,
+
+    #[serde(default)]
+    pub language_label: String,
+    /// SVG file name in src/assets/, without the extension.
+    #[serde(default)]
+    pub language_icon: Option<String>,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Skill item names from content/skills.toml.
+    #[serde(default)]
+    pub skills: Vec<String>,
+    #[serde(default)]
+    pub status: Option<Status>,
+    /// "YYYY-MM", only optional for pinned entries.
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub featured: bool,
+    #[serde(default)]
+    pub pinned: bool,
+
+    pub repo_url: String,
+    pub media: MediaEntry,
+    #[serde(default)]
+    pub demo_url: String,
+    #[serde(default = "default_demo_label")]
+    pub demo_label: Localized,
+    #[serde(default = "default_cta_label")]
+    pub cta_label: Localized,
+
+    /// Loaded from content/case_studies/<id>.toml when present.
+    #[serde(skip)]
+    pub case_study: Option<CaseStudyEntry>,
+    /// From content/repo_stats.json, see build/repo_stats.rs.
+    #[serde(skip)]
+    pub stats: Option<StatsEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CaseStudyEntry {
+    pub problem: String,
+    pub architecture: String,
+    #[serde(default)]
+    pub challenges: Vec<String>,
+    #[serde(default)]
+    pub results: Vec<String>,
+    #[serde(default)]
+    pub media: Vec<MediaEntry>,
+    #[serde(default)]
+    pub links: Vec<LinkEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LinkEntry {
+    pub label: String,
+    pub url: String,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Active,
+    Complete,
+    Archived,
+}
+
+impl Status {
+    fn rust_path(self) -> &'static str {
+        match self {
+            Status::Active => "ProjectStatus::Active",
+            Status::Complete => "ProjectStatus::Complete",
+            Status::Archived => "ProjectStatus::Archived",
+        }
+    }
+}
+
+fn default_demo_label() -> Localized {
+    Localized::same("Demo")
+}
+
+fn default_cta_label() -> Localized {
+    Localized::same("GitHub")
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SkillsFile {
+    group: Vec<GroupEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GroupEntry {
+    pub title: Localized,
+    pub subtitle: Localized,
+    pub items: Vec<SkillEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SkillEntry {
+    pub name: String,
+    /// From the name, see `skill_id`; set once the file is loaded.
+    #[serde(skip)]
+    pub id: String,
+    pub proficiency: Proficiency,
+    #[serde(default)]
+    pub years: Option<u8>,
+    /// "YYYY-MM"
+    #[serde(default)]
+    pub last_used: Option<String>,
+    #[serde(default)]
+    pub note: Option<Localized>,
+    /// Project ids; merged with each project's `skills` list.
+    #[serde(default)]
+    pub projects: Vec<String>,
+}
+
+/// Mirrors `Proficiency` in src/utils/skills_data.rs; serde rejects anything else.
+#[derive(Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+#[serde(rename_all = "lowercase")]
+pub enum Proficiency {
+    Learning,
+    Familiar,
+    Comfortable,
+    Solid,
+    Strong,
+}
+
+impl Proficiency {
+    pub fn rust_path(self) -> &'static str {
+        match self {
+            Proficiency::Learning => "Proficiency::Learning",
+            Proficiency::Familiar => "Proficiency::Familiar",
+            Proficiency::Comfortable => "Proficiency::Comfortable",
+            Proficiency::Solid => "Proficiency::Solid",
+            Proficiency::Strong => "Proficiency::Strong",
+        }
+    }
+}
+
+pub struct Content {
+    pub root: PathBuf,
+    pub projects: Vec<ProjectEntry>,
+    pub groups: Vec<GroupEntry>,
+    pub posts: Vec<PostEntry>,
+    pub strings: Catalogs,
+    pub site: SiteConfig,
+    pub profile: Profile,
+    pub resume: ResumeConfig,
+}
+
+/// Parse content/ and run every check, collecting all errors instead of
+/// stopping at the first one.
+pub fn load(root: &Path) -> Result<Content, Vec<String>> {
+    let mut errors = Vec::new();
+
+    let mut projects = parse::<ProjectsFile>(&root.join("content/projects.toml"), &mut errors)
+        .map(|f| f.project)
+        .unwrap_or_default();
+    load_case_studies(root, &mut projects, &mut errors);
+    repo_stats::attach(root, &mut projects, &mut errors);
+    let mut groups = parse::<SkillsFile>(&root.join("content/skills.toml"), &mut errors)
+        .map(|f| f.group)
+        .unwrap_or_default();
+    for item in groups.iter_mut().flat_map(|g| &mut g.items) {
+        item.id = skill_id(&item.name);
+    }
+    let posts = posts::load(root, &mut errors);
+    let strings = i18n::load(root, &mut errors);
+    let site = site::load(root, &mut errors);
+    let profile = profile::load(root, &mut errors);
+    let resume = resume::load(root, &mut errors);
+    let (Some(site), Some(profile), Some(resume)) = (site, profile, resume) else {
+        return Err(errors);
+    };
+
+    let mut content = Content {
+        root: root.to_path_buf(),
+        projects,
+        groups,
+        posts,
+        strings,
+        site,
+        profile,
+        resume,
+    };
+
+    check_projects(&content, &mut errors);
+    check_skills(&content, &mut errors);
+    link_skills(&mut content, &mut errors);
+    profile::apply(&mut content, &mut errors);
+    resume::check(&content, &mut errors);
+
+    if errors.is_empty() {
+        Ok(content)
+    } else {
+        Err(errors)
+    }
+}
+
+pub fn parse<T: for<'de> Deserialize<'de>>(path: &Path, errors: &mut Vec<String>) -> Option<T> {
+    let text = match fs::read_to_string(path) {
+        Ok(t) => t,
+        Err(e) => {
+            errors.push(format!("{}: {e}", path.display()));
+            return None;
+        }
+    };
+    match toml::from_str(&text) {
+        Ok(v) => Some(v),
+        Err(e) => {
+            errors.push(format!("{}: {e}", path.display()));
+            None
+        }
+    }
+}
+
+/// Attach content/case_studies/<id>.toml to the project with that id.
+fn load_case_studies(root: &Path, projects: &mut [ProjectEntry], errors: &mut Vec<String>) {
+    let dir = root.join("content/case_studies");
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return;
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let id = path.file_stem().unwrap_or_default().to_string_lossy();
+        let Some(project) = projects.iter_mut().find(|p| p.id == id && !p.pinned) else {
+            errors.push(format!(
+                "{}: no project with id `{id}` to attach this case study to",
+                path.display()
+            ));
+            continue;
+        };
+        project.case_study = parse::<CaseStudyEntry>(&path, errors);
+    }
+}
+
+fn check_projects(content: &Content, errors: &mut Vec<String>) {
+    let mut ids = HashSet::new();
+
+    for p in &content.projects {
+        let at = format!("project `{}`", p.id);
+
+        if p.id.is_empty() {
+            errors.push(format!("project `{}` has an empty id", p.name.base()));
+        }
+        if !ids.insert(p.id.as_str()) {
+            errors.push(format!("{at}: duplicate id"));
+        }
+
+        media::check(&content.root, &at, &p.media, errors);
+
+        if !is_valid_url(&p.repo_url) {
+            errors.push(format!("{at}: malformed repo_url `{}`", p.repo_url));
+        }
+        if !p.demo_url.is_empty() && !is_valid_url(&p.demo_url) {
+            errors.push(format!("{at}: malformed demo_url `{}`", p.demo_url));
+        }
+
+        if let Some(icon) = &p.language_icon
+            && !asset_path(&content.root, &format!("{icon}.svg")).is_file()
+        {
+            errors.push(format!("{at}: unknown language_icon `{icon}`"));
+        }
+
+        let mut tags = HashSet::new();
+        for tag in &p.tags {
+            if tag.trim().is_empty() || tag.contains(',') {
+                errors.push(format!("{at}: invalid tag `{tag}`"));
+            }
+            if !tags.insert(tag.to_lowercase()) {
+                errors.push(format!("{at}: duplicate tag `{tag}`"));
+            }
+        }
+
+        match &p.date {
+            Some(date) if !is_year_month(date) => {
+                errors.push(format!("{at}: date `{date}` is not YYYY-MM"));
+            }
+            None if !p.pinned => errors.push(format!("{at}: missing date")),
+            _ => {}
+        }
+        if p.status.is_none() && !p.pinned {
+            errors.push(format!("{at}: missing status"));
+        }
+
+        if let Some(cs) = &p.case_study {
+            let at = format!("{at} case study");
+            for m in &cs.media {
+                media::check(&content.root, &at, m, errors);
+            }
+            for l in &cs.links {
+                if !is_valid_url(&l.url) {
+                    errors.push(format!("{at}: malformed link `{}`", l.url));
+                }
+            }
+        }
+    }
+
+    if let Some(pos) = content.projects.iter().position(|p| p.pinned)
+        && content.projects[pos..].iter().any(|p| !p.pinned)
+    {
+        errors.push("pinned projects must come last in content/projects.toml".into());
+    }
+}
+
+fn is_year_month(date: &str) -> bool {
+    let Some((year, month)) = date.split_once('-') else {
+        return false;
+    };
+    year.len() == 4
+        && year.bytes().all(|b| b.is_ascii_digit())
+        && month.len() == 2
+        && matches!(month.parse::<u8>(), Ok(1..=12))
+}
+
+/// "C#" -> "c-sharp": the skill's id in URLs and element ids, which has to
+/// tell apart names that only differ in punctuation.
+fn skill_id(name: &str) -> String {
+    slugify(&name.replace('#', " sharp").replace('+', " plus"))
+}
+
+fn check_skills(content: &Content, errors: &mut Vec<String>) {
+    let mut titles = HashSet::new();
+    let mut all_names = HashSet::new();
+    let mut ids: HashMap<&str, &str> = HashMap::new();
+
+    for g in &content.groups {
+        if !titles.insert(g.title.base()) {
+            errors.push(format!("skill group `{}`: duplicate title", g.title.base()));
+        }
+
+        let mut names = HashSet::new();
+        for item in &g.items {
+            let at = format!("skill `{}` in `{}`", item.name, g.title.base());
+            if !names.insert(item.name.as_str()) {
+                errors.push(format!("{at}: duplicate item"));
+            } else if !all_names.insert(item.name.as_str()) {
+                errors.push(format!("{at}: name already used in another group"));
+            } else if item.id.is_empty() {
+                errors.push(format!("{at}: the name has no letters or digits"));
+            } else if let Some(other) = ids.insert(&item.id, &item.name) {
+                errors.push(format!(
+                    "{at}: id `{}` is the same as `{other}`'s, rename one of them",
+                    item.id
+                ));
+            }
+            if let Some(date) = &item.last_used
+                && !is_year_month(date)
+            {
+                errors.push(format!("{at}: last_used `{date}` is not YYYY-MM"));
+            }
+        }
+    }
+}
+
+/// Check both directions of the skill <-> project relation and make each side
+/// list the other, so the generated tables agree no matter where it was declared.
+fn link_skills(content: &mut Content, errors: &mut Vec<String>) {
+    let mut pairs: Vec<(String, String)> = Vec::new(); // (project id, skill name)
+
+    for p in &content.projects {
+        for skill in &p.skills {
+            let exists = content
+                .groups
+                .iter()
+                .any(|g| g.items.iter().any(|i| &i.name == skill));
+            if exists {
+                pairs.push((p.id.clone(), skill.clone()));
+            } else {
+                errors.push(format!("project `{}`: unknown skill `{skill}`", p.id));
+            }
+        }
+    }
+
+    for g in &content.groups {
+        for item in &g.items {
+            for id in &item.projects {
+                let exists = content.projects.iter().any(|p| &p.id == id && !p.pinned);
+                if exists {
+                    pairs.push((id.clone(), item.name.clone()));
+                } else {
+                    errors.push(format!("skill `{}`: unknown project id `{id}`", item.name));
+                }
+            }
+        }
+    }
+
+    // rebuild both lists in content order
+    for p in &mut content.projects {
+        let mut skills: Vec<String> = Vec::new();
+        for g in &content.groups {
+            for item in &g.items {
+                if pairs.iter().any(|(id, s)| *id == p.id && *s == item.name) {
+                    skills.push(item.name.clone());
+                }
+            }
+        }
+        p.skills = skills;
+    }
+    for g in &mut content.groups {
+        for item in &mut g.items {
+            item.projects = content
+                .projects
+                .iter()
+                .filter(|p| pairs.iter().any(|(id, s)| *id == p.id && *s == item.name))
+                .map(|p| p.id.clone())
+                .collect();
+        }
+    }
+}
+
+pub fn asset_path(root: &Path, file: &str) -> PathBuf {
+    root.join("src/assets").join(file)
+}
+
+/// Cheap sanity check; we only link to plain http(s) pages.
+pub fn is_valid_url(url: &str) -> bool {
+    let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+
+    host.contains('.')
+        && !host.starts_with('.')
+        && !host.ends_with('.')
+        && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+pub fn write(content: &Content, out_dir: &Path) -> io::Result<()> {
+    fs::write(out_dir.join("projects_data.rs"), projects_rs(content))?;
+    fs::write(out_dir.join("skills_data.rs"), skills_rs(content))?;
+    fs::write(out_dir.join("search_index.rs"), search::index_rs(content))?;
+    fs::write(
+        out_dir.join("i18n_data.rs"),
+        i18n::strings_rs(&content.strings),
+    )?;
+    fs::write(
+        out_dir.join("posts_data.rs"),
+        posts::posts_rs(&content.posts),
+    )?;
+    fs::write(out_dir.join("site_data.rs"), site::site_rs(&content.site))?;
+    fs::write(
+        out_dir.join("profile_data.rs"),
+        profile::profile_rs(&content.profile, &content.root),
+    )?;
+    fs::write(
+        out_dir.join("json_ld_data.rs"),
+        json_ld::json_ld_rs(content),
+    )?;
+    fs::write(
+        out_dir.join("resume_data.rs"),
+        resume::resume_rs(&content.resume),
+    )?;
+    Ok(())
+}
+
+/// Constant name used for an icon, e.g. `rust` -> `RUST_SVG`.
+fn icon_const(icon: &str) -> String {
+    format!("{}_SVG", icon.to_uppercase().replace(['-', '.'], "_"))
+}
+
+fn projects_rs(content: &Content) -> String {
+    let mut out = String::from("// @generated by build/content.rs from content/projects.toml\n\n");
+
+    let mut icons: Vec<&str> = content
+        .projects
+        .iter()
+        .filter_map(|p| p.language_icon.as_deref())
+        .collect();
+    icons.sort_unstable();
+    icons.dedup();
+
+    for icon in icons {
+        let path = asset_path(&content.root, &format!("{icon}.svg"));
+        writeln!(
+            out,
+            "pub const {}: &str = include_str!({:?});",
+            icon_const(icon),
+            path.display().to_string()
+        )
+        .unwrap();
+    }
+
+    out.push_str("\npub const PROJECTS: &[Project] = &[\n");
+    for p in &content.projects {
+        let svg = match &p.language_icon {
+            Some(icon) => format!("Some({})", icon_const(icon)),
+            None => "None".into(),
+        };
+        let status = match p.status {
+            Some(s) => format!("Some({})", s.rust_path()),
+            None => "None".into(),
+        };
+        let case_study = match &p.case_study {
+            Some(cs) => format!("Some(&{})", case_study_rs(cs)),
+            None => "None".into(),
+        };
+        // pinned entries have no page of their own to preview
+        let og_image = (!p.pinned).then(|| og_images::project_path(&p.id));
+        let stats = match &p.stats {
+            Some(s) => format!(
+                "Some(RepoStats {{ stars: {}, last_commit: {:?}, languages: &{:?}, archived: {} }})",
+                s.stars, s.last_commit, s.languages, s.archived
+            ),
+            None => "None".into(),
+        };
+        writeln!(
+            out,
+            "    Project {{
+        id: {:?},
+        name: {},
+        description: {},
+        language_label: {:?},
+        language_svg: {svg},
+        tags: &{:?},
+        skills: &{:?},
+        status: {status},
+        date: {:?},
+        featured: {},
+        pinned: {},
+        repo_url: {:?},
+        media: {},
+        demo_url: {:?},
+        demo_label: {},
+        cta_label: {},
+        case_study: {case_study},
+        stats: {stats},
+        og_image: {og_image:?},
+    }},",
+            p.id,
+            p.name.rust(),
+            p.description.rust(),
+            p.language_label,
+            p.tags,
+            p.skills,
+            p.date.as_deref(),
+            p.featured,
+            p.pinned,
+            p.repo_url,
+            p.media.rust(),
+            p.demo_url,
+            p.demo_label.rust(),
+            p.cta_label.rust(),
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+fn case_study_rs(cs: &CaseStudyEntry) -> String {
+    let media: Vec<String> = cs.media.iter().map(MediaEntry::rust).collect();
+    let links: Vec<String> = cs
+        .links
+        .iter()
+        .map(|l| format!("Link {{ label: {:?}, url: {:?} }}", l.label, l.url))
+        .collect();
+
+    format!(
+        "CaseStudy {{
+            problem: &{:?},
+            architecture: &{:?},
+            challenges: &{:?},
+            results: &{:?},
+            media: &[{}],
+            links: &[{}],
+        }}",
+        paragraphs(&cs.problem),
+        paragraphs(&cs.architecture),
+        cs.challenges,
+        cs.results,
+        media.join(", "),
+        links.join(", "),
+    )
+}
+
+/// Split on blank lines and unwrap hard-wrapped lines inside each paragraph.
+fn paragraphs(text: &str) -> Vec<String> {
+    text.split("\n\n")
+        .map(|p| p.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+fn skills_rs(content: &Content) -> String {
+    let mut out = String::from("// @generated by build/content.rs from content/skills.toml\n\n");
+
+    out.push_str("pub const GROUPS: &[SkillGroup] = &[\n");
+    for g in &content.groups {
+        writeln!(
+            out,
+            "    SkillGroup {{\n        title: {},\n        subtitle: {},\n        items: &[",
+            g.title.rust(),
+            g.subtitle.rust()
+        )
+        .unwrap();
+        for item in &g.items {
+            writeln!(
+                out,
+                "            SkillItem {{ id: {:?}, name: {:?}, proficiency: {}, years: {:?}, last_used: {:?}, note: {}, projects: &{:?} }},",
+                item.id,
+                item.name,
+                item.proficiency.rust_path(),
+                item.years,
+                item.last_used.as_deref(),
+                item.note.as_ref().map_or("None".into(), |n| format!("Some({})", n.rust())),
+                item.projects,
+            )
+            .unwrap();
+        }
+        out.push_str("        ],\n    },\n");
+    }
+    out.push_str("];\n");
+    out
+}
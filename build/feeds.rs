@@ -0,0 +1,176 @@
+// Atom (feed.xml) and JSON Feed (feed.json) for posts and project updates,
+// built from the same content the site compiles in. Entries are in the
+// fallback locale with absolute URLs from `SiteConfig::base_url`.
+
+use std::fmt::Write as _;
+
+use serde_json::json;
+
+use crate::content::Content;
+
+struct Item {
+    url: String,
+    title: String,
+    summary: String,
+    html: Option<String>,
+    tags: Vec<String>,
+    /// RFC 3339
+    published: String,
+    updated: String,
+}
+
+/// Posts and dated project pages, most recently updated first.
+fn items(content: &Content) -> Vec<Item> {
+    let site = &content.site;
+    let mut items: Vec<Item> = content
+        .posts
+        .iter()
+        .map(|p| {
+            let date = format!("{}T00:00:00Z", p.date);
+            Item {
+                url: site.url(&format!("/posts/{}", p.slug)),
+                title: p.title.clone(),
+                summary: p.summary.clone(),
+                html: Some(p.html.clone()),
+                tags: p.tags.clone(),
+                published: date.clone(),
+                updated: date,
+            }
+        })
+        .collect();
+
+    for p in content.projects.iter().filter(|p| !p.pinned) {
+        let Some(date) = &p.date else {
+            continue;
+        };
+        let published = format!("{date}-01T00:00:00Z");
+        // the stats snapshot knows about pushes after the project started
+        let updated = p
+            .stats
+            .as_ref()
+            .map_or_else(|| published.clone(), |s| s.last_commit.clone());
+        items.push(Item {
+            url: site.url(&format!("/projects/{}", p.id)),
+            title: p.name.base().to_string(),
+            summary: p.description.base().to_string(),
+            html: None,
+            tags: p.tags.clone(),
+            published,
+            updated,
+        });
+    }
+
+    // all timestamps are UTC RFC 3339, so string order is time order
+    items.sort_by(|a, b| b.updated.cmp(&a.updated).then(a.url.cmp(&b.url)));
+    items
+}
+
+pub fn atom(content: &Content) -> String {
+    let site = &content.site;
+    let items = items(content);
+    let updated = items.first().map_or("1970-01-01T00:00:00Z", |i| &i.updated);
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    out.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    writeln!(out, "  <id>{}</id>", escape(&site.url("/"))).unwrap();
+    writeln!(out, "  <title>{}</title>", escape(&site.title)).unwrap();
+    writeln!(out, "  <subtitle>{}</subtitle>", escape(&site.description)).unwrap();
+    writeln!(out, "  <updated>{updated}</updated>").unwrap();
+    writeln!(
+        out,
+        "  <author><name>{}</name></author>",
+        escape(&content.profile.name)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "  <link rel=\"alternate\" type=\"text/html\" href=\"{}\" />",
+        escape(&site.url("/"))
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "  <link rel=\"self\" type=\"application/atom+xml\" href=\"{}\" />",
+        escape(&site.url("/feed.xml"))
+    )
+    .unwrap();
+
+    for item in &items {
+        let url = escape(&item.url);
+        // xml:base so in-page links like "#heading" resolve against the entry
+        writeln!(out, "  <entry xml:base=\"{url}\">").unwrap();
+        writeln!(out, "    <id>{url}</id>").unwrap();
+        writeln!(out, "    <title>{}</title>", escape(&item.title)).unwrap();
+        writeln!(
+            out,
+            "    <link rel=\"alternate\" type=\"text/html\" href=\"{url}\" />"
+        )
+        .unwrap();
+        writeln!(out, "    <published>{}</published>", item.published).unwrap();
+        writeln!(out, "    <updated>{}</updated>", item.updated).unwrap();
+        for tag in &item.tags {
+            writeln!(out, "    <category term=\"{}\" />", escape(tag)).unwrap();
+        }
+        writeln!(out, "    <summary>{}</summary>", escape(&item.summary)).unwrap();
+        if let Some(html) = &item.html {
+            writeln!(out, "    <content type=\"html\">{}</content>", escape(html)).unwrap();
+        }
+        out.push_str("  </entry>\n");
+    }
+    out.push_str("</feed>\n");
+    out
+}
+
+/// JSON Feed 1.1, https://www.jsonfeed.org/version/1.1/
+pub fn json(content: &Content) -> String {
+    let site = &content.site;
+    let items: Vec<serde_json::Value> = items(content)
+        .into_iter()
+        .map(|i| {
+            let mut item = json!({
+                "id": i.url,
+                "url": i.url,
+                "title": i.title,
+                "summary": i.summary,
+                "date_published": i.published,
+                "date_modified": i.updated,
+                "tags": i.tags,
+            });
+            // content_text is required when there is no content_html
+            match i.html {
+                Some(html) => item["content_html"] = html.into(),
+                None => item["content_text"] = i.summary.into(),
+            }
+            item
+        })
+        .collect();
+
+    let feed = json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": site.title,
+        "description": site.description,
+        "home_page_url": site.url("/"),
+        "feed_url": site.url("/feed.json"),
+        "authors": [{ "name": content.profile.name }],
+        "items": items,
+    });
+    let mut out = serde_json::to_string_pretty(&feed).expect("feed is plain JSON");
+    out.push('\n');
+    out
+}
+
+/// Escape text for XML/HTML element content and attribute values.
+pub fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
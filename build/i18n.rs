@@ -0,0 +1,277 @@
+// Loads the translation catalogs in content/i18n/<locale>.toml, checks that
+// every locale has exactly the same keys, and emits the `Strings` struct plus
+// one const per locale for src/utils/i18n.rs.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// Mirrors `Locale` in src/utils/i18n.rs; the first one is the fallback.
+pub const LOCALES: [&str; 2] = ["en", "pt"];
+
+pub enum Value {
+    Str(String),
+    List(Vec<String>),
+}
+
+/// Flattened `section.key` -> value, one map per entry of `LOCALES`.
+pub struct Catalogs(Vec<BTreeMap<String, Value>>);
+
+impl Catalogs {
+    /// Every string, list entries included, as (locale, key, text).
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str, &str)> {
+        LOCALES.iter().zip(&self.0).flat_map(|(locale, catalog)| {
+            catalog.iter().flat_map(move |(key, value)| {
+                let texts: Vec<&str> = match value {
+                    Value::Str(s) => vec![s],
+                    Value::List(l) => l.iter().map(String::as_str).collect(),
+                };
+                texts.into_iter().map(move |t| (*locale, key.as_str(), t))
+            })
+        })
+    }
+
+    /// The string at `key` in `LOCALES[l]`.
+    pub fn text(&self, l: usize, key: &str) -> Option<&str> {
+        match self.0[l].get(key)? {
+            Value::Str(s) => Some(s),
+            Value::List(_) => None,
+        }
+    }
+
+    /// Rewrite every string with `f(locale index, text)`.
+    pub fn map(&mut self, mut f: impl FnMut(usize, &str) -> String) {
+        for (l, catalog) in self.0.iter_mut().enumerate() {
+            for value in catalog.values_mut() {
+                match value {
+                    Value::Str(s) => *s = f(l, s),
+                    Value::List(list) => {
+                        for s in list {
+                            *s = f(l, s);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn load(root: &Path, errors: &mut Vec<String>) -> Catalogs {
+    let mut catalogs = Vec::new();
+    for locale in LOCALES {
+        let path = root.join(format!("content/i18n/{locale}.toml"));
+        let mut flat = BTreeMap::new();
+        match fs::read_to_string(&path).map(|t| t.parse::<toml::Table>()) {
+            Ok(Ok(table)) => flatten(&path.display().to_string(), "", table, &mut flat, errors),
+            Ok(Err(e)) => errors.push(format!("{}: {e}", path.display())),
+            Err(e) => errors.push(format!("{}: {e}", path.display())),
+        }
+        catalogs.push(flat);
+    }
+
+    let catalogs = Catalogs(catalogs);
+    check(&catalogs, errors);
+    catalogs
+}
+
+fn flatten(
+    file: &str,
+    prefix: &str,
+    table: toml::Table,
+    out: &mut BTreeMap<String, Value>,
+    errors: &mut Vec<String>,
+) {
+    for (name, value) in table {
+        let key = if prefix.is_empty() {
+            name
+        } else {
+            format!("{prefix}.{name}")
+        };
+        if !key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.')
+        {
+            errors.push(format!(
+                "{file}: key `{key}` must be lowercase ascii, digits and `_`"
+            ));
+            continue;
+        }
+
+        match value {
+            toml::Value::String(s) => {
+                out.insert(key, Value::Str(s));
+            }
+            toml::Value::Table(t) => flatten(file, &key, t, out, errors),
+            toml::Value::Array(items) => {
+                let list: Option<Vec<String>> = items
+                    .into_iter()
+                    .map(|v| v.as_str().map(str::to_string))
+                    .collect();
+                match list {
+                    Some(list) => {
+                        out.insert(key, Value::List(list));
+                    }
+                    None => errors.push(format!("{file}: `{key}` must be a list of strings")),
+                }
+            }
+            _ => errors.push(format!(
+                "{file}: `{key}` must be a string or a list of strings"
+            )),
+        }
+    }
+}
+
+/// Same keys, same kinds, same list lengths and same `{placeholders}` in
+/// every locale, so a lookup can never come back empty at runtime.
+fn check(catalogs: &Catalogs, errors: &mut Vec<String>) {
+    let all_keys: BTreeSet<&String> = catalogs.0.iter().flat_map(|c| c.keys()).collect();
+    let (base_locale, base) = (LOCALES[0], &catalogs.0[0]);
+
+    for key in all_keys {
+        for (locale, catalog) in LOCALES.iter().zip(&catalogs.0) {
+            let at = format!("content/i18n/{locale}.toml");
+            let Some(value) = catalog.get(key) else {
+                errors.push(format!("{at}: missing key `{key}`"));
+                continue;
+            };
+            let Some(base_value) = base.get(key) else {
+                continue;
+            };
+
+            match (base_value, value) {
+                (Value::Str(a), Value::Str(b)) => {
+                    if placeholders(a) != placeholders(b) {
+                        errors.push(format!(
+                            "{at}: `{key}` has placeholders {:?}, {base_locale} has {:?}",
+                            placeholders(b),
+                            placeholders(a)
+                        ));
+                    }
+                }
+                (Value::List(a), Value::List(b)) => {
+                    if a.len() != b.len() {
+                        errors.push(format!(
+                            "{at}: `{key}` has {} entries, {base_locale} has {}",
+                            b.len(),
+                            a.len()
+                        ));
+                    }
+                }
+                _ => errors.push(format!(
+                    "{at}: `{key}` is not the same kind of value as in {base_locale}"
+                )),
+            }
+        }
+    }
+}
+
+/// `"Used in: {names}"` -> `{"names"}`
+fn placeholders(s: &str) -> BTreeSet<&str> {
+    s.split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+/// `nav.home` -> `nav_home`
+fn field(key: &str) -> String {
+    key.replace('.', "_")
+}
+
+pub fn strings_rs(catalogs: &Catalogs) -> String {
+    let mut out = String::from("// @generated by build/i18n.rs from content/i18n/\n\n");
+
+    out.push_str("/// One field per catalog key (`nav.home` -> `nav_home`).\n");
+    out.push_str("pub struct Strings {\n");
+    for (key, value) in &catalogs.0[0] {
+        let ty = match value {
+            Value::Str(_) => "&'static str",
+            Value::List(_) => "&'static [&'static str]",
+        };
+        writeln!(out, "    pub {}: {ty},", field(key)).unwrap();
+    }
+    out.push_str("}\n");
+
+    for (locale, catalog) in LOCALES.iter().zip(&catalogs.0) {
+        writeln!(
+            out,
+            "\npub const {}: Strings = Strings {{",
+            locale.to_uppercase()
+        )
+        .unwrap();
+        for (key, value) in catalog {
+            match value {
+                Value::Str(s) => writeln!(out, "    {}: {s:?},", field(key)).unwrap(),
+                Value::List(l) => writeln!(out, "    {}: &{l:?},", field(key)).unwrap(),
+            }
+        }
+        out.push_str("};\n");
+    }
+    out
+}
+
+/// A content string that's either the same in every locale or given per
+/// locale as `{ en = "...", pt = "..." }`, in which case all are required.
+#[derive(Deserialize)]
+#[serde(try_from = "LocalizedRaw")]
+pub struct Localized(Vec<String>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LocalizedRaw {
+    Same(String),
+    PerLocale(BTreeMap<String, String>),
+}
+
+impl TryFrom<LocalizedRaw> for Localized {
+    type Error = String;
+
+    fn try_from(raw: LocalizedRaw) -> Result<Self, String> {
+        match raw {
+            LocalizedRaw::Same(s) => Ok(Localized::same(&s)),
+            LocalizedRaw::PerLocale(mut map) => {
+                let texts = LOCALES
+                    .iter()
+                    .map(|l| map.remove(*l).ok_or(format!("missing `{l}` translation")))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match map.keys().next() {
+                    Some(extra) => Err(format!("unknown locale `{extra}`")),
+                    None => Ok(Localized(texts)),
+                }
+            }
+        }
+    }
+}
+
+impl Localized {
+    pub fn same(s: &str) -> Self {
+        Localized(vec![s.to_string(); LOCALES.len()])
+    }
+
+    /// Text in the fallback locale.
+    pub fn base(&self) -> &str {
+        &self.0[0]
+    }
+
+    /// Text in `LOCALES[l]`.
+    pub fn get(&self, l: usize) -> &str {
+        &self.0[l]
+    }
+
+    pub fn all(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    /// `Text { en: "...", pt: "..." }`
+    pub fn rust(&self) -> String {
+        let fields: Vec<String> = LOCALES
+            .iter()
+            .zip(&self.0)
+            .map(|(l, s)| format!("{l}: {s:?}"))
+            .collect();
+        format!("Text {{ {} }}", fields.join(", "))
+    }
+}
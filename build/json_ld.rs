@@ -0,0 +1,140 @@
+// schema.org structured data (JSON-LD) for search engines: the site owner as
+// a `Person`, each project page as `SoftwareSourceCode` and each post as a
+// `BlogPosting`. Emitted as ready-to-embed JSON strings for
+// src/utils/json_ld.rs; src/utils/meta.rs puts the page's one in <head>.
+
+use std::fmt::Write as _;
+
+use serde_json::{Value, json};
+
+use crate::content::{Content, ProjectEntry};
+use crate::i18n::LOCALES;
+use crate::og_images;
+use crate::posts::PostEntry;
+
+const CONTEXT: &str = "https://schema.org";
+
+/// `@id` of the `Person`, so every other node can point at it.
+fn person_id(content: &Content) -> String {
+    content.site.url("/#person")
+}
+
+fn person(content: &Content) -> Value {
+    let profile = &content.profile;
+    let mut person = json!({
+        "@type": "Person",
+        "@id": person_id(content),
+        "name": profile.name,
+        "givenName": profile.first_name,
+        "jobTitle": profile.role.base(),
+        "description": profile.headline.base(),
+        "email": format!("mailto:{}", profile.email),
+        "url": content.site.url("/"),
+        "sameAs": profile.socials.iter().map(|s| s.url.as_str()).collect::<Vec<_>>(),
+        "knowsLanguage": profile
+            .languages
+            .iter()
+            .map(|l| json!({ "@type": "Language", "name": l.name.base() }))
+            .collect::<Vec<_>>(),
+    });
+    if let Some(location) = &profile.location {
+        person["homeLocation"] = json!({ "@type": "Place", "name": location.base() });
+    }
+    person
+}
+
+fn project(content: &Content, p: &ProjectEntry) -> Value {
+    let mut code = json!({
+        "@type": "SoftwareSourceCode",
+        "name": p.name.base(),
+        "description": p.description.base(),
+        "url": content.site.url(&format!("/projects/{}", p.id)),
+        "codeRepository": p.repo_url,
+        "image": content.site.url(&og_images::project_path(&p.id)),
+        "author": { "@id": person_id(content) },
+    });
+    if !p.language_label.is_empty() {
+        code["programmingLanguage"] = p.language_label.as_str().into();
+    }
+    if !p.tags.is_empty() {
+        code["keywords"] = p.tags.join(", ").into();
+    }
+    if let Some(date) = &p.date {
+        code["dateCreated"] = date.as_str().into();
+    }
+    if let Some(stats) = &p.stats {
+        code["dateModified"] = stats.last_commit.as_str().into();
+    }
+    code
+}
+
+fn post(content: &Content, p: &PostEntry) -> Value {
+    json!({
+        "@type": "BlogPosting",
+        "headline": p.title,
+        "description": p.summary,
+        "url": content.site.url(&format!("/posts/{}", p.slug)),
+        "datePublished": p.date,
+        "keywords": p.tags.join(", "),
+        "inLanguage": LOCALES[0],
+        "author": { "@id": person_id(content) },
+    })
+}
+
+/// The main page: who it's about, the site, and every project it lists.
+fn main_page(content: &Content) -> Value {
+    let mut graph = vec![
+        person(content),
+        json!({
+            "@type": "WebSite",
+            "name": content.site.title,
+            "description": content.site.description,
+            "url": content.site.url("/"),
+            "inLanguage": LOCALES,
+            "author": { "@id": person_id(content) },
+        }),
+    ];
+    graph.extend(
+        content
+            .projects
+            .iter()
+            .filter(|p| !p.pinned)
+            .map(|p| project(content, p)),
+    );
+    json!({ "@context": CONTEXT, "@graph": graph })
+}
+
+/// A page about `node`, with the `Person` its `author` refers to.
+fn page(content: &Content, node: Value) -> Value {
+    json!({ "@context": CONTEXT, "@graph": [node, person(content)] })
+}
+
+/// JSON that can go inside <script> as is: `<` is escaped, so no string in
+/// it can close the element.
+fn embeddable(value: &Value) -> String {
+    value.to_string().replace('<', "\\u003c")
+}
+
+/// `MAIN`, `PROJECTS` and `POSTS` for src/utils/json_ld.rs.
+pub fn json_ld_rs(content: &Content) -> String {
+    let mut out = String::from("// @generated by build/json_ld.rs\n\n");
+    writeln!(
+        out,
+        "pub const MAIN: &str = {:?};\n",
+        embeddable(&main_page(content))
+    )
+    .unwrap();
+
+    out.push_str("const PROJECTS: &[(&str, &str)] = &[\n");
+    for p in content.projects.iter().filter(|p| !p.pinned) {
+        let json = embeddable(&page(content, project(content, p)));
+        writeln!(out, "    ({:?}, {json:?}),", p.id).unwrap();
+    }
+    out.push_str("];\n\nconst POSTS: &[(&str, &str)] = &[\n");
+    for p in &content.posts {
+        let json = embeddable(&page(content, post(content, p)));
+        writeln!(out, "    ({:?}, {json:?}),", p.slug).unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
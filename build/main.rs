@@ -0,0 +1,52 @@
+// Build script: turns the TOML files in content/ into the static data tables
+// the site compiles in (see src/utils/projects_data.rs, skills_data.rs and
+// search.rs), plus the feeds served next to index.html (see build/site.rs).
+
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+mod content;
+mod feeds;
+mod i18n;
+mod json_ld;
+mod media;
+mod og_images;
+mod posts;
+mod profile;
+mod pwa;
+mod repo_stats;
+mod resume;
+mod search;
+mod site;
+mod slug;
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed=build");
+    println!("cargo:rerun-if-changed=content");
+    println!("cargo:rerun-if-changed=src/assets");
+    // theme-color, see build/pwa.rs
+    println!("cargo:rerun-if-changed=index.html");
+    println!("cargo:rerun-if-env-changed={}", site::BASE_URL_ENV);
+    println!("cargo:rerun-if-env-changed=CARGO_TARGET_DIR");
+
+    let content = match content::load(&root) {
+        Ok(c) => c,
+        Err(errors) => fail(&errors),
+    };
+
+    content::write(&content, &out_dir).expect("cannot write generated content");
+    site::write(&content, &site::out_dir(&root)).expect("cannot write site files");
+}
+
+/// Print every content error and abort the build.
+fn fail(errors: &[String]) -> ! {
+    eprintln!("content/ has {} error(s):", errors.len());
+    for e in errors {
+        eprintln!("  - {e}");
+    }
+    process::exit(1);
+}
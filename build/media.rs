@@ -0,0 +1,180 @@
+// Project media and its renditions: the same recording as WebM, MP4 and/or
+// an animated GIF, plus a still poster frame. Every file an entry names is
+// checked to exist; a recording with a GIF but no poster of its own gets the
+// GIF's first frame, written to posters/ with the other site files.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use resvg::tiny_skia::{IntSize, Pixmap, PixmapPaint, Transform};
+use serde::Deserialize;
+
+use crate::content::{self, ASSET_PREFIX, Content};
+
+/// Where derived posters are served from, in the same `../` form as
+/// `ASSET_PREFIX`.
+const POSTER_PREFIX: &str = "../posters/";
+const POSTER_EXTENSIONS: [&str; 5] = ["png", "jpg", "jpeg", "webp", "avif"];
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MediaEntry {
+    pub label: String,
+    #[serde(default)]
+    pub webm: Option<String>,
+    #[serde(default)]
+    pub mp4: Option<String>,
+    #[serde(default)]
+    pub gif: Option<String>,
+    /// A still: what a recording shows before it plays, or the whole thing
+    /// for a screenshot.
+    #[serde(default)]
+    pub poster: Option<String>,
+}
+
+impl MediaEntry {
+    fn is_video(&self) -> bool {
+        self.webm.is_some() || self.mp4.is_some()
+    }
+
+    /// `(field, path)` for every rendition declared.
+    fn renditions(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("webm", &self.webm),
+            ("mp4", &self.mp4),
+            ("gif", &self.gif),
+            ("poster", &self.poster),
+        ]
+        .into_iter()
+        .filter_map(|(field, path)| Some((field, path.as_deref()?)))
+    }
+
+    /// The poster, declared or derived from the GIF.
+    fn poster(&self) -> Option<String> {
+        match (&self.poster, &self.gif) {
+            (Some(poster), _) => Some(poster.clone()),
+            (None, Some(gif)) if self.is_video() => Some(derived_poster(gif)),
+            _ => None,
+        }
+    }
+
+    /// `Media { .. }` for src/utils/projects_data.rs.
+    pub fn rust(&self) -> String {
+        format!(
+            "Media {{ label: {:?}, webm: {:?}, mp4: {:?}, gif: {:?}, poster: {:?} }}",
+            self.label,
+            self.webm,
+            self.mp4,
+            self.gif,
+            self.poster(),
+        )
+    }
+}
+
+/// "../assets/obsctl.gif" -> "../posters/obsctl.png"
+fn derived_poster(gif: &str) -> String {
+    format!("{POSTER_PREFIX}{}.png", file_stem(gif))
+}
+
+fn file_stem(path: &str) -> &str {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.rsplit_once('.').map_or(name, |(stem, _)| stem)
+}
+
+pub fn check(root: &Path, at: &str, media: &MediaEntry, errors: &mut Vec<String>) {
+    if media.renditions().next().is_none() {
+        errors.push(format!(
+            "{at}: media `{}` needs at least one of webm, mp4, gif or poster",
+            media.label
+        ));
+    }
+    for (field, src) in media.renditions() {
+        let Some(file) = src.strip_prefix(ASSET_PREFIX) else {
+            errors.push(format!(
+                "{at}: media {field} `{src}` must start with `{ASSET_PREFIX}`"
+            ));
+            continue;
+        };
+        if !content::asset_path(root, file).is_file() {
+            errors.push(format!(
+                "{at}: media {field} `{src}` not found in src/assets/"
+            ));
+        }
+        let extension = file.rsplit_once('.').map_or("", |(_, e)| e);
+        let expected = match field {
+            "poster" => POSTER_EXTENSIONS.contains(&extension),
+            _ => extension == field,
+        };
+        if !expected {
+            errors.push(format!(
+                "{at}: media {field} `{src}` has the wrong file type"
+            ));
+        }
+    }
+}
+
+/// Every media entry in the projects and their case studies.
+fn all(content: &Content) -> impl Iterator<Item = &MediaEntry> {
+    content
+        .projects
+        .iter()
+        .flat_map(|p| std::iter::once(&p.media).chain(p.case_study.iter().flat_map(|cs| &cs.media)))
+}
+
+/// Write the derived posters into `out_dir` (posters/ among the site files).
+pub fn write_posters(content: &Content, out_dir: &Path) -> io::Result<()> {
+    if out_dir.exists() {
+        fs::remove_dir_all(out_dir)?;
+    }
+    fs::create_dir_all(out_dir)?;
+    for media in all(content).filter(|m| m.poster.is_none() && m.is_video()) {
+        let Some(gif) = &media.gif else {
+            continue;
+        };
+        let file = gif.trim_start_matches(ASSET_PREFIX);
+        let png = first_frame(&content::asset_path(&content.root, file))?
+            .encode_png()
+            .map_err(io::Error::other)?;
+        fs::write(out_dir.join(format!("{}.png", file_stem(file))), png)?;
+    }
+    Ok(())
+}
+
+/// The first frame of a GIF, on the GIF's full canvas.
+fn first_frame(path: &Path) -> io::Result<Pixmap> {
+    let at = |e: &dyn std::fmt::Display| io::Error::other(format!("{}: {e}", path.display()));
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options
+        .read_info(fs::File::open(path)?)
+        .map_err(|e| at(&e))?;
+    let (width, height) = (u32::from(decoder.width()), u32::from(decoder.height()));
+    let frame = decoder
+        .read_next_frame()
+        .map_err(|e| at(&e))?
+        .ok_or_else(|| at(&"no frames"))?;
+
+    // the GIF's colors are straight, tiny-skia's premultiplied
+    let mut rgba = frame.buffer.to_vec();
+    for px in rgba.as_chunks_mut::<4>().0 {
+        let a = u16::from(px[3]);
+        for c in &mut px[..3] {
+            *c = ((u16::from(*c) * a + 127) / 255) as u8;
+        }
+    }
+    let size = IntSize::from_wh(u32::from(frame.width), u32::from(frame.height))
+        .ok_or_else(|| at(&"empty frame"))?;
+    let image = Pixmap::from_vec(rgba, size).ok_or_else(|| at(&"bad frame size"))?;
+
+    let mut canvas = Pixmap::new(width, height).ok_or_else(|| at(&"empty image"))?;
+    canvas.draw_pixmap(
+        i32::from(frame.left),
+        i32::from(frame.top),
+        image.as_ref(),
+        &PixmapPaint::default(),
+        Transform::identity(),
+        None,
+    );
+    Ok(canvas)
+}
@@ -0,0 +1,267 @@
+// 1200x630 link preview images (Open Graph / Twitter cards): one per project
+// page and one for the rest of the site, drawn as SVG over the same sky as
+// src/components/background.rs and rasterized with resvg. Text is set in the
+// DejaVu fonts compiled into the `dejavu` crate, so the output doesn't depend
+// on what fonts the machine running the build has.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg::{self, fontdb};
+
+use crate::content::{Content, ProjectEntry};
+use crate::feeds::escape;
+
+const WIDTH: u32 = 1200;
+const HEIGHT: u32 = 630;
+
+/// The glass card the text sits on.
+const CARD_X: f32 = 80.0;
+const CARD_Y: f32 = 140.0;
+const CARD_W: f32 = 1040.0;
+const CARD_H: f32 = 350.0;
+const PADDING: f32 = 64.0;
+const ICON_SIZE: f32 = 150.0;
+
+/// DejaVu Sans Bold is about this wide per character, in ems; good enough
+/// to shrink long names and wrap the site description.
+const BOLD_CHAR_EM: f32 = 0.68;
+const REGULAR_CHAR_EM: f32 = 0.56;
+
+/// Where a project's image ends up, relative to the site root.
+pub fn project_path(id: &str) -> String {
+    format!("/og/{id}.png")
+}
+
+pub const SITE_PATH: &str = "/og/site.png";
+
+/// Render every image into `out_dir` (og/ among the site files), replacing what a
+/// previous build left there.
+pub fn write(content: &Content, out_dir: &Path) -> io::Result<()> {
+    if out_dir.exists() {
+        fs::remove_dir_all(out_dir)?;
+    }
+    fs::create_dir_all(out_dir)?;
+    let fonts = fonts();
+
+    let site = &content.site;
+    let host = host(&site.base_url);
+    let png = render(
+        &fonts,
+        &site_svg(&site.title, &site.description, host),
+        None,
+    )?;
+    fs::write(out_dir.join(file_name(SITE_PATH)), png)?;
+
+    for p in content.projects.iter().filter(|p| !p.pinned) {
+        let icon = match &p.language_icon {
+            Some(icon) => Some(fs::read_to_string(
+                content.root.join("src/assets").join(format!("{icon}.svg")),
+            )?),
+            None => None,
+        };
+        let svg = project_svg(p, icon.is_some(), host);
+        let png = render(&fonts, &svg, icon.as_deref())?;
+        fs::write(out_dir.join(file_name(&project_path(&p.id))), png)?;
+    }
+    Ok(())
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+/// "araujoviana.github.io/rusty-web-portfolio"
+pub fn host(base_url: &str) -> &str {
+    base_url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+}
+
+fn fonts() -> Arc<fontdb::Database> {
+    let mut db = fontdb::Database::new();
+    db.load_font_data(dejavu::sans::regular().to_vec());
+    db.load_font_data(dejavu::sans::bold().to_vec());
+    db.set_sans_serif_family("DejaVu Sans");
+    Arc::new(db)
+}
+
+/// Rasterize `svg`, with the language icon `icon` (an SVG document of its
+/// own) drawn in the card's icon slot.
+fn render(fonts: &Arc<fontdb::Database>, svg: &str, icon: Option<&str>) -> io::Result<Vec<u8>> {
+    let opts = usvg::Options {
+        fontdb: fonts.clone(),
+        ..Default::default()
+    };
+    let mut pixmap = Pixmap::new(WIDTH, HEIGHT).expect("non-zero size");
+
+    let tree = usvg::Tree::from_str(svg, &opts).map_err(io::Error::other)?;
+    resvg::render(&tree, Transform::identity(), &mut pixmap.as_mut());
+
+    if let Some(icon) = icon {
+        let tree = usvg::Tree::from_str(icon, &opts).map_err(io::Error::other)?;
+        let size = tree.size();
+        let scale = ICON_SIZE / size.width().max(size.height());
+        let y = CARD_Y + (CARD_H - ICON_SIZE) / 2.0;
+        let transform = Transform::from_scale(scale, scale).post_translate(CARD_X + PADDING, y);
+        resvg::render(&tree, transform, &mut pixmap.as_mut());
+    }
+
+    pixmap.encode_png().map_err(io::Error::other)
+}
+
+fn project_svg(p: &ProjectEntry, has_icon: bool, host: &str) -> String {
+    let text_x = if has_icon {
+        CARD_X + PADDING * 1.5 + ICON_SIZE
+    } else {
+        CARD_X + PADDING
+    };
+    let text_w = CARD_X + CARD_W - PADDING - text_x;
+
+    let name = p.name.base();
+    let size = fit(name, text_w, 84.0, BOLD_CHAR_EM);
+    let mid = CARD_Y + CARD_H / 2.0;
+    let mut text = format!(
+        "<text x=\"{text_x}\" y=\"{}\" font-size=\"{size}\" font-weight=\"bold\" fill=\"#ffffff\">{}</text>",
+        mid + size * 0.1,
+        escape(name)
+    );
+    if !p.language_label.is_empty() {
+        text.push_str(&format!(
+            "<text x=\"{text_x}\" y=\"{}\" font-size=\"36\" fill=\"#ffffff\" fill-opacity=\"0.75\">{}</text>",
+            mid + size * 0.1 + 64.0,
+            escape(&p.language_label)
+        ));
+    }
+    page_svg(&p.id, &text, host)
+}
+
+fn site_svg(title: &str, description: &str, host: &str) -> String {
+    let x = CARD_X + PADDING;
+    let text_w = CARD_W - PADDING * 2.0;
+    let size = fit(title, text_w, 76.0, BOLD_CHAR_EM);
+    let lines = wrap(description, text_w, 34.0);
+
+    let block = size + lines.len() as f32 * 48.0 + 24.0;
+    let top = CARD_Y + (CARD_H - block) / 2.0 + size * 0.8;
+    let mut text = format!(
+        "<text x=\"{x}\" y=\"{top}\" font-size=\"{size}\" font-weight=\"bold\" fill=\"#ffffff\">{}</text>",
+        escape(title)
+    );
+    for (i, line) in lines.iter().enumerate() {
+        text.push_str(&format!(
+            "<text x=\"{x}\" y=\"{}\" font-size=\"34\" fill=\"#ffffff\" fill-opacity=\"0.75\">{}</text>",
+            top + 24.0 + (i + 1) as f32 * 48.0,
+            escape(line)
+        ));
+    }
+    page_svg("site", &text, host)
+}
+
+/// Sky, clouds, the glass card with `text` on it, and the site's address.
+/// The sky runs from `Background`'s top-of-page hsl(205 90% 65%) to about
+/// halfway down its scroll, hsl(218 65% 36%), under the same shading.
+fn page_svg(seed: &str, text: &str, host: &str) -> String {
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}" font-family="DejaVu Sans">
+  <defs>
+    <linearGradient id="sky" x1="0" y1="0" x2="0" y2="1">
+      <stop offset="0" stop-color="#55b3f6"/>
+      <stop offset="1" stop-color="#204c97"/>
+    </linearGradient>
+    <linearGradient id="shade" x1="0" y1="0" x2="0" y2="1">
+      <stop offset="0" stop-color="#ffffff" stop-opacity="0.1"/>
+      <stop offset="0.5" stop-color="#000000" stop-opacity="0"/>
+      <stop offset="1" stop-color="#000000" stop-opacity="0.5"/>
+    </linearGradient>
+    <linearGradient id="glass" x1="0" y1="0" x2="1" y2="1">
+      <stop offset="0" stop-color="#ffffff" stop-opacity="0.22"/>
+      <stop offset="1" stop-color="#ffffff" stop-opacity="0.06"/>
+    </linearGradient>
+    <filter id="soft" x="-50%" y="-50%" width="200%" height="200%">
+      <feGaussianBlur stdDeviation="8"/>
+    </filter>
+  </defs>
+  <rect width="100%" height="100%" fill="url(#sky)"/>
+  <rect width="100%" height="100%" fill="url(#shade)"/>
+  <g filter="url(#soft)">{clouds}</g>
+  <rect x="{CARD_X}" y="{CARD_Y}" width="{CARD_W}" height="{CARD_H}" rx="36" fill="url(#glass)" stroke="#ffffff" stroke-opacity="0.2" stroke-width="2"/>
+  {text}
+  <text x="{CARD_X}" y="{footer_y}" font-size="28" fill="#ffffff" fill-opacity="0.7">{host}</text>
+</svg>"##,
+        clouds = clouds(seed),
+        footer_y = HEIGHT as f32 - 56.0,
+        host = escape(host),
+    )
+}
+
+/// A few puffy clouds, placed by a generator seeded from `seed` so each page
+/// gets its own sky and every build the same one.
+fn clouds(seed: &str) -> String {
+    let mut state = seed.bytes().fold(0x811c_9dc5_u32, |h, b| {
+        (h ^ u32::from(b)).wrapping_mul(0x0100_0193)
+    });
+    let mut next = move || {
+        // xorshift32
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        (state % 10_000) as f32 / 10_000.0
+    };
+
+    let mut out = String::new();
+    for i in 0..5 {
+        let cx = (i as f32 + 0.2 + next() * 0.6) * WIDTH as f32 / 5.0;
+        let cy = 40.0 + next() * (HEIGHT as f32 - 120.0);
+        let r = 45.0 + next() * 50.0;
+        let opacity = 0.35 + next() * 0.4;
+        out.push_str(&format!(
+            "<g fill=\"#ffffff\" fill-opacity=\"{opacity:.2}\">"
+        ));
+        // a wide base and three bumps on top
+        out.push_str(&format!(
+            "<ellipse cx=\"{cx:.1}\" cy=\"{cy:.1}\" rx=\"{:.1}\" ry=\"{:.1}\"/>",
+            r * 2.0,
+            r * 0.6
+        ));
+        for (dx, dy, k) in [(-0.9, -0.35, 0.7), (0.0, -0.6, 1.0), (0.9, -0.3, 0.65)] {
+            out.push_str(&format!(
+                "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.1}\"/>",
+                cx + dx * r,
+                cy + dy * r,
+                r * k
+            ));
+        }
+        out.push_str("</g>");
+    }
+    out
+}
+
+/// The largest font size up to `max` that fits `text` on one line.
+fn fit(text: &str, width: f32, max: f32, char_em: f32) -> f32 {
+    let chars = text.chars().count().max(1) as f32;
+    (width / (chars * char_em)).min(max).floor()
+}
+
+/// Greedy word wrap at `size`, at most three lines.
+fn wrap(text: &str, width: f32, size: f32) -> Vec<String> {
+    let max_chars = (width / (size * REGULAR_CHAR_EM)) as usize;
+    let mut lines: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= max_chars => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+    if lines.len() > 3 {
+        lines.truncate(3);
+        lines[2].push('…');
+    }
+    lines
+}
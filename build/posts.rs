@@ -0,0 +1,282 @@
+// Loads content/posts/<slug>.md: TOML front matter between `+++` lines, then
+// Markdown that is rendered to HTML here, with an id and a `#` anchor on every
+// heading and a table of contents built from the `##`/`###` ones.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pulldown_cmark::{CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd, html};
+use serde::Deserialize;
+
+use crate::slug::slugify;
+
+const FENCE: &str = "+++";
+const WORDS_PER_MINUTE: usize = 200;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FrontMatter {
+    title: String,
+    /// "YYYY-MM-DD"
+    date: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    summary: String,
+}
+
+pub struct PostEntry {
+    pub slug: String,
+    pub title: String,
+    pub date: String,
+    pub tags: Vec<String>,
+    pub summary: String,
+    pub reading_minutes: usize,
+    pub toc: Vec<TocEntry>,
+    pub html: String,
+}
+
+pub struct TocEntry {
+    pub level: u8,
+    pub id: String,
+    pub title: String,
+}
+
+/// Every post, newest first.
+pub fn load(root: &Path, errors: &mut Vec<String>) -> Vec<PostEntry> {
+    let Ok(entries) = fs::read_dir(root.join("content/posts")) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+
+    let mut posts: Vec<PostEntry> = paths
+        .iter()
+        .filter_map(|path| load_post(path, errors))
+        .collect();
+    // dates are zero-padded, so string order is date order
+    posts.sort_by(|a, b| b.date.cmp(&a.date).then(a.slug.cmp(&b.slug)));
+    posts
+}
+
+fn load_post(path: &Path, errors: &mut Vec<String>) -> Option<PostEntry> {
+    let at = path.display().to_string();
+    let slug = path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+
+    if slug.is_empty()
+        || !slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        errors.push(format!(
+            "{at}: file name must be a slug (lowercase ascii, digits and `-`)"
+        ));
+    }
+
+    let text = match fs::read_to_string(path) {
+        Ok(t) => t,
+        Err(e) => {
+            errors.push(format!("{at}: {e}"));
+            return None;
+        }
+    };
+    let Some((front, body)) = split_front_matter(&text) else {
+        errors.push(format!(
+            "{at}: must start with front matter between `{FENCE}` lines"
+        ));
+        return None;
+    };
+    let front: FrontMatter = match toml::from_str(front) {
+        Ok(f) => f,
+        Err(e) => {
+            errors.push(format!("{at}: front matter: {e}"));
+            return None;
+        }
+    };
+
+    if front.title.trim().is_empty() {
+        errors.push(format!("{at}: empty title"));
+    }
+    if front.summary.trim().is_empty() {
+        errors.push(format!("{at}: empty summary"));
+    }
+    if chrono::NaiveDate::parse_from_str(&front.date, "%Y-%m-%d").is_err() {
+        errors.push(format!("{at}: date `{}` is not YYYY-MM-DD", front.date));
+    }
+    for tag in &front.tags {
+        if tag.trim().is_empty() || tag.contains(',') {
+            errors.push(format!("{at}: invalid tag `{tag}`"));
+        }
+    }
+
+    let (html, toc, words) = render(&at, body, errors);
+
+    Some(PostEntry {
+        slug,
+        title: front.title,
+        date: front.date,
+        tags: front.tags,
+        summary: front.summary,
+        reading_minutes: words.div_ceil(WORDS_PER_MINUTE).max(1),
+        toc,
+        html,
+    })
+}
+
+fn is_anchor(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn split_front_matter(text: &str) -> Option<(&str, &str)> {
+    let rest = text.strip_prefix(FENCE)?.strip_prefix('\n')?;
+    let end = rest.find(&format!("\n{FENCE}\n"))?;
+    Some((&rest[..end], &rest[end + FENCE.len() + 2..]))
+}
+
+/// Markdown -> (HTML, table of contents, word count).
+fn render(at: &str, markdown: &str, errors: &mut Vec<String>) -> (String, Vec<TocEntry>, usize) {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_HEADING_ATTRIBUTES;
+    let events: Vec<Event> = Parser::new_ext(markdown, options).collect();
+
+    let mut out: Vec<Event> = Vec::with_capacity(events.len());
+    let mut toc = Vec::new();
+    let mut ids = HashSet::new();
+    let mut words = 0;
+
+    let mut i = 0;
+    while i < events.len() {
+        match &events[i] {
+            Event::Start(Tag::Heading { level, id, .. }) => {
+                let level = *level;
+                if level == HeadingLevel::H1 {
+                    errors.push(format!(
+                        "{at}: use `##` and below, the post title is the page's only h1"
+                    ));
+                }
+
+                // the heading's inline events, up to its end tag
+                let end = events[i..]
+                    .iter()
+                    .position(|e| matches!(e, Event::End(TagEnd::Heading(_))))
+                    .map_or(events.len(), |p| i + p);
+                let inner = &events[i + 1..end];
+
+                let title: String = inner
+                    .iter()
+                    .filter_map(|e| match e {
+                        Event::Text(t) | Event::Code(t) => Some(t.as_ref()),
+                        _ => None,
+                    })
+                    .collect();
+                words += title.split_whitespace().count();
+
+                // a custom {#id} goes into the markup as is, so it has to be
+                // safe there and in a URL fragment
+                let mut base = match id.as_deref() {
+                    Some(id) if is_anchor(id) => id.to_string(),
+                    Some(id) => {
+                        errors.push(format!(
+                            "{at}: heading id `{id}` may only use letters, digits, `-` and `_`"
+                        ));
+                        slugify(id)
+                    }
+                    None => slugify(&title),
+                };
+                if base.is_empty() {
+                    // a heading with no letters or digits at all
+                    base.push_str("section");
+                }
+                let mut anchor = base.clone();
+                let mut n = 2;
+                while !ids.insert(anchor.clone()) {
+                    anchor = format!("{base}-{n}");
+                    n += 1;
+                }
+
+                let tag = level as u8;
+                if matches!(level, HeadingLevel::H2 | HeadingLevel::H3) {
+                    toc.push(TocEntry {
+                        level: tag,
+                        id: anchor.clone(),
+                        title,
+                    });
+                }
+
+                out.push(Event::Html(CowStr::from(format!(
+                    "<h{tag} id=\"{anchor}\">"
+                ))));
+                out.extend(inner.iter().cloned());
+                out.push(Event::Html(CowStr::from(format!(
+                    " <a class=\"anchor\" href=\"#{anchor}\" aria-hidden=\"true\">#</a></h{tag}>\n"
+                ))));
+                i = end + 1;
+                continue;
+            }
+            Event::Text(t) => words += t.split_whitespace().count(),
+            _ => {}
+        }
+        out.push(events[i].clone());
+        i += 1;
+    }
+
+    let mut html_out = String::new();
+    html::push_html(&mut html_out, out.into_iter());
+    (html_out, toc, words)
+}
+
+pub fn posts_rs(posts: &[PostEntry]) -> String {
+    let mut out = String::from("// @generated by build/posts.rs from content/posts/\n\n");
+
+    out.push_str("pub const POSTS: &[Post] = &[\n");
+    for p in posts {
+        let toc: Vec<String> = p
+            .toc
+            .iter()
+            .map(|t| {
+                format!(
+                    "TocEntry {{ level: {}, id: {:?}, title: {:?} }}",
+                    t.level, t.id, t.title
+                )
+            })
+            .collect();
+        writeln!(
+            out,
+            "    Post {{
+        slug: {:?},
+        title: {:?},
+        date: {:?},
+        tags: &{:?},
+        summary: {:?},
+        reading_minutes: {},
+        toc: &[{}],
+        html: {:?},
+    }},",
+            p.slug,
+            p.title,
+            p.date,
+            p.tags,
+            p.summary,
+            p.reading_minutes,
+            toc.join(", "),
+            p.html,
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
@@ -0,0 +1,267 @@
+// Identity and contact details from content/profile.toml: checked, filled
+// into the UI strings and site title wherever those say `{profile.x}`, and
+// emitted as `PROFILE` for src/utils/profile_data.rs.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::content::{self, Content};
+use crate::i18n::{LOCALES, Localized};
+
+const CONFIG: &str = "content/profile.toml";
+const PLACEHOLDER: &str = "{profile.";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Full name, for bylines and metadata.
+    pub name: String,
+    /// What the site calls its owner in running text.
+    pub first_name: String,
+    pub headline: Localized,
+    pub role: Localized,
+    pub email: String,
+    #[serde(default)]
+    pub location: Option<Localized>,
+    #[serde(rename = "language")]
+    pub languages: Vec<LanguageEntry>,
+    #[serde(rename = "social")]
+    pub socials: Vec<SocialEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LanguageEntry {
+    pub name: Localized,
+    pub level: Localized,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SocialEntry {
+    pub label: String,
+    pub url: String,
+    /// SVG file name in src/assets/, without the extension.
+    pub icon: String,
+}
+
+impl Profile {
+    /// `{profile.field}` in locale `l` (an index into `LOCALES`).
+    fn field(&self, field: &str, l: usize) -> Option<String> {
+        Some(match field {
+            "name" => self.name.clone(),
+            "first_name" => self.first_name.clone(),
+            "role" => self.role.get(l).to_string(),
+            // "Portuguese (native) / English (advanced)"
+            "languages" => self
+                .languages
+                .iter()
+                .map(|lang| format!("{} ({})", lang.name.get(l), lang.level.get(l)))
+                .collect::<Vec<_>>()
+                .join(" / "),
+            _ => return None,
+        })
+    }
+
+    /// Replace every `{profile.field}` in `text`.
+    fn fill(&self, text: &str, l: usize) -> Result<String, String> {
+        let mut out = String::new();
+        let mut rest = text;
+        while let Some(start) = rest.find(PLACEHOLDER) {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + PLACEHOLDER.len()..];
+            let Some((field, tail)) = after.split_once('}') else {
+                return Err(format!("unterminated `{PLACEHOLDER}...`"));
+            };
+            match self.field(field, l) {
+                Some(value) => out.push_str(&value),
+                None => return Err(format!("unknown placeholder `{PLACEHOLDER}{field}}}`")),
+            }
+            rest = tail;
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+}
+
+pub fn load(root: &Path, errors: &mut Vec<String>) -> Option<Profile> {
+    let profile = content::parse::<Profile>(&root.join(CONFIG), errors)?;
+    check(root, &profile, errors);
+    Some(profile)
+}
+
+fn check(root: &Path, profile: &Profile, errors: &mut Vec<String>) {
+    if profile.name.trim().is_empty() || profile.first_name.trim().is_empty() {
+        errors.push(format!("{CONFIG}: `name` and `first_name` can't be empty"));
+    }
+    if !is_valid_email(&profile.email) {
+        errors.push(format!("{CONFIG}: malformed email `{}`", profile.email));
+    }
+    if profile.languages.is_empty() {
+        errors.push(format!("{CONFIG}: list at least one [[language]]"));
+    }
+
+    let mut labels = Vec::new();
+    for s in &profile.socials {
+        let at = format!("{CONFIG}: social `{}`", s.label);
+        if labels.contains(&s.label) {
+            errors.push(format!("{at}: duplicate label"));
+        }
+        labels.push(s.label.clone());
+        if !content::is_valid_url(&s.url) {
+            errors.push(format!("{at}: malformed url `{}`", s.url));
+        }
+        if !content::asset_path(root, &format!("{}.svg", s.icon)).is_file() {
+            errors.push(format!("{at}: unknown icon `{}`", s.icon));
+        }
+    }
+}
+
+/// `someone@example.com`; just enough to catch typos like a missing `@`.
+fn is_valid_email(email: &str) -> bool {
+    let Some((user, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !user.is_empty()
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !domain.contains('@')
+        && !email.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+/// The profile is the one place identity details live: the UI strings and
+/// site title must refer to them with `{profile.x}` rather than repeat them,
+/// so they can't drift apart again. Then fill those placeholders in.
+pub fn apply(content: &mut Content, errors: &mut Vec<String>) {
+    let profile = &content.profile;
+
+    let mut texts: Vec<(String, &str)> = content
+        .strings
+        .iter()
+        .map(|(locale, key, s)| (format!("content/i18n/{locale}.toml: `{key}`"), s))
+        .collect();
+    texts.push(("content/site.toml: `title`".into(), &content.site.title));
+    texts.push((
+        "content/site.toml: `description`".into(),
+        &content.site.description,
+    ));
+    for (at, text) in texts {
+        for problem in repeated_details(profile, text) {
+            errors.push(format!("{at} {problem}"));
+        }
+    }
+
+    content.strings.map(|l, s| match profile.fill(s, l) {
+        Ok(filled) => filled,
+        Err(e) => {
+            errors.push(format!("content/i18n/{}.toml: {e} in `{s}`", LOCALES[l]));
+            s.to_string()
+        }
+    });
+    // the site's title is in the fallback locale
+    match profile.fill(&content.site.title, 0) {
+        Ok(title) => content.site.title = title,
+        Err(e) => errors.push(format!("content/site.toml: {e} in `title`")),
+    }
+}
+
+/// What `text` spells out that it should take from the profile instead.
+fn repeated_details(profile: &Profile, text: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+    if contains_word(text, &profile.first_name) {
+        problems.push(format!(
+            "names `{}`, write `{PLACEHOLDER}first_name}}` instead",
+            profile.first_name
+        ));
+    }
+    if let Some(email) = text
+        .split(|c: char| c.is_whitespace() || "()<>,;:\"'".contains(c))
+        .find(|word| is_valid_email(word.trim_end_matches('.')))
+    {
+        problems.push(format!(
+            "has the email address `{email}`, Contact and the footer show {CONFIG}'s"
+        ));
+    }
+    for s in &profile.socials {
+        let host = s.url.split('/').nth(2).unwrap_or_default();
+        let host = host.trim_start_matches("www.");
+        if !host.is_empty() && text.contains(host) {
+            problems.push(format!(
+                "links to {host}, the {} profile belongs in {CONFIG}",
+                s.label
+            ));
+        }
+    }
+    problems
+}
+
+/// Whether `word` appears in `text` on its own, not inside a longer word.
+fn contains_word(text: &str, word: &str) -> bool {
+    text.match_indices(word).any(|(i, _)| {
+        let before = text[..i].chars().next_back();
+        let after = text[i + word.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// `PROFILE` for src/utils/profile_data.rs.
+pub fn profile_rs(profile: &Profile, root: &Path) -> String {
+    let mut out = String::from("// @generated by build/profile.rs from content/profile.toml\n\n");
+    let socials: Vec<String> = profile
+        .socials
+        .iter()
+        .map(|s| {
+            let icon = content::asset_path(root, &format!("{}.svg", s.icon));
+            format!(
+                "Social {{ label: {:?}, url: {:?}, icon_svg: include_str!({:?}) }}",
+                s.label,
+                s.url,
+                icon.display().to_string()
+            )
+        })
+        .collect();
+    let languages: Vec<String> = profile
+        .languages
+        .iter()
+        .map(|l| {
+            format!(
+                "SpokenLanguage {{ name: {}, level: {} }}",
+                l.name.rust(),
+                l.level.rust()
+            )
+        })
+        .collect();
+    let location = match &profile.location {
+        Some(l) => format!("Some({})", l.rust()),
+        None => "None".into(),
+    };
+    writeln!(
+        out,
+        "pub const PROFILE: Profile = Profile {{
+    name: {:?},
+    first_name: {:?},
+    headline: {},
+    role: {},
+    email: {:?},
+    location: {location},
+    languages: &[
+        {},
+    ],
+    socials: &[
+        {},
+    ],
+}};",
+        profile.name,
+        profile.first_name,
+        profile.headline.rust(),
+        profile.role.rust(),
+        profile.email,
+        languages.join(",\n        "),
+        socials.join(",\n        "),
+    )
+    .unwrap();
+    out
+}
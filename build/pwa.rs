@@ -0,0 +1,105 @@
+// What makes the site installable: manifest.webmanifest, and the PNG icons
+// it lists, rendered from src/assets/icon.svg. The service worker that makes
+// it work offline needs the file names Trunk picks and the prerendered pages,
+// so scripts/service_worker.sh writes that one after scripts/prerender.sh.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg;
+use serde_json::json;
+
+use crate::content::Content;
+use crate::i18n::LOCALES;
+
+pub const MANIFEST_PATH: &str = "/manifest.webmanifest";
+const ICON: &str = "src/assets/icon.svg";
+/// What browsers want to offer installing: 192 for the home screen, 512 for
+/// splash screens.
+const ICON_SIZES: [u32; 2] = [192, 512];
+
+pub fn icon_path(size: u32) -> String {
+    format!("/icons/icon-{size}.png")
+}
+
+/// Write the manifest and icons into `out_dir`, the site files directory.
+pub fn write(content: &Content, out_dir: &Path) -> io::Result<()> {
+    let icon = usvg::Tree::from_data(
+        &fs::read(content.root.join(ICON))?,
+        &usvg::Options::default(),
+    )
+    .map_err(|e| io::Error::other(format!("{ICON}: {e}")))?;
+    fs::create_dir_all(out_dir.join("icons"))?;
+    for size in ICON_SIZES {
+        let png = render(&icon, size).encode_png().map_err(io::Error::other)?;
+        fs::write(out_dir.join(relative(&icon_path(size))), png)?;
+    }
+
+    let theme_color = theme_color(&fs::read_to_string(content.root.join("index.html"))?)
+        .ok_or_else(|| io::Error::other("index.html: no <meta name=\"theme-color\">"))?;
+    fs::write(
+        out_dir.join(relative(MANIFEST_PATH)),
+        manifest(content, &theme_color),
+    )
+}
+
+/// Paths in the manifest are resolved against the manifest's own URL, so
+/// relative ones keep working under a `--public-url` prefix.
+fn relative(path: &str) -> &str {
+    path.trim_start_matches('/')
+}
+
+fn manifest(content: &Content, theme_color: &str) -> String {
+    let mut icons: Vec<_> = ICON_SIZES
+        .iter()
+        .map(|size| {
+            json!({
+                "src": relative(&icon_path(*size)),
+                "sizes": format!("{size}x{size}"),
+                "type": "image/png",
+            })
+        })
+        .collect();
+    icons.push(json!({
+        "src": "assets/icon.svg",
+        "sizes": "any",
+        "type": "image/svg+xml",
+    }));
+    icons.push(json!({
+        "src": "assets/favicon.ico",
+        "sizes": "48x48 32x32 16x16",
+        "type": "image/x-icon",
+    }));
+    let manifest = json!({
+        "name": content.site.title,
+        "short_name": content.profile.first_name,
+        "description": content.site.description,
+        "lang": LOCALES[0],
+        "start_url": "./",
+        "scope": "./",
+        "display": "standalone",
+        "theme_color": theme_color,
+        "background_color": theme_color,
+        "icons": icons,
+    });
+    serde_json::to_string_pretty(&manifest).unwrap()
+}
+
+/// The `content` of index.html's `<meta name="theme-color">`, so the
+/// manifest can't disagree with it.
+fn theme_color(index_html: &str) -> Option<String> {
+    let tag = index_html
+        .split('<')
+        .find(|tag| tag.starts_with("meta") && tag.contains("name=\"theme-color\""))?;
+    let (_, rest) = tag.split_once("content=\"")?;
+    Some(rest.split('"').next()?.to_string())
+}
+
+fn render(icon: &usvg::Tree, size: u32) -> Pixmap {
+    let mut out = Pixmap::new(size, size).expect("non-zero size");
+    let k = size as f32 / icon.size().width().max(icon.size().height());
+    resvg::render(icon, Transform::from_scale(k, k), &mut out.as_mut());
+    out
+}
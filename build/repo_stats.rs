@@ -0,0 +1,85 @@
+// Reads content/repo_stats.json, the GitHub snapshot written by
+// scripts/fetch_repo_stats.sh. The build never goes to the network: a missing
+// file or entry only means the card shows no stats.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::content::ProjectEntry;
+
+const SNAPSHOT: &str = "content/repo_stats.json";
+
+/// Only `repos` is read; `fetched_at` is there for whoever reviews the diff.
+#[derive(Deserialize)]
+struct Snapshot {
+    repos: BTreeMap<String, StatsEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StatsEntry {
+    pub stars: u32,
+    /// RFC 3339, e.g. "2025-11-02T18:04:11Z"
+    pub last_commit: String,
+    /// Most-used first.
+    #[serde(default)]
+    pub languages: Vec<String>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// Attach each snapshot entry to the project with the same id. A malformed
+/// snapshot is an error; anything merely missing is a warning.
+pub fn attach(root: &Path, projects: &mut [ProjectEntry], errors: &mut Vec<String>) {
+    let path = root.join(SNAPSHOT);
+    let text = match fs::read_to_string(&path) {
+        Ok(t) => t,
+        Err(e) => {
+            warn(&format!(
+                "{SNAPSHOT}: {e}, project cards will show no repo stats"
+            ));
+            return;
+        }
+    };
+    let snapshot: Snapshot = match serde_json::from_str(&text) {
+        Ok(s) => s,
+        Err(e) => {
+            errors.push(format!("{SNAPSHOT}: {e}"));
+            return;
+        }
+    };
+
+    for (id, stats) in snapshot.repos {
+        let at = format!("{SNAPSHOT}: repos.{id}");
+        if chrono::DateTime::parse_from_rfc3339(&stats.last_commit).is_err() {
+            errors.push(format!(
+                "{at}: last_commit `{}` is not an RFC 3339 timestamp",
+                stats.last_commit
+            ));
+            continue;
+        }
+        match projects.iter_mut().find(|p| p.id == id && !p.pinned) {
+            Some(p) => p.stats = Some(stats),
+            None => warn(&format!("{at}: no project with this id, entry ignored")),
+        }
+    }
+
+    let missing: Vec<&str> = projects
+        .iter()
+        .filter(|p| !p.pinned && p.stats.is_none())
+        .map(|p| p.id.as_str())
+        .collect();
+    if !missing.is_empty() {
+        warn(&format!(
+            "{SNAPSHOT}: no stats for {}; run scripts/fetch_repo_stats.sh to refresh",
+            missing.join(", ")
+        ));
+    }
+}
+
+fn warn(msg: &str) {
+    println!("cargo:warning={msg}");
+}
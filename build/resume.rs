@@ -0,0 +1,598 @@
+// The résumé: which sections /resume and cv.pdf show and which projects they
+// list (content/resume.toml), `RESUME` for src/utils/resume_data.rs, and
+// cv.pdf itself. The PDF is laid out here, in the fallback locale, with the
+// standard Helvetica fonts every PDF reader has, so nothing gets embedded.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+use pdf_writer::types::{ActionType, AnnotationType};
+use pdf_writer::{Content as Ops, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use serde::Deserialize;
+
+use crate::content::{self, Content, Proficiency};
+use crate::og_images;
+
+const CONFIG: &str = "content/resume.toml";
+/// Where the PDF ends up, relative to the site root.
+pub const PDF_PATH: &str = "/cv.pdf";
+/// The page showing the same thing, which the PDF's footer links back to.
+const PAGE_PATH: &str = "/resume";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResumeConfig {
+    pub sections: Vec<Section>,
+    /// Project ids, in the order they're listed.
+    pub projects: Vec<String>,
+    #[serde(default)]
+    pub min_proficiency: Option<Proficiency>,
+}
+
+/// Mirrors `ResumeSection` in src/utils/resume_data.rs.
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Section {
+    Summary,
+    Skills,
+    Projects,
+    Languages,
+}
+
+impl Section {
+    fn rust_path(self) -> &'static str {
+        match self {
+            Section::Summary => "ResumeSection::Summary",
+            Section::Skills => "ResumeSection::Skills",
+            Section::Projects => "ResumeSection::Projects",
+            Section::Languages => "ResumeSection::Languages",
+        }
+    }
+
+    /// Catalog key of the section's heading.
+    fn title_key(self) -> &'static str {
+        match self {
+            Section::Summary => "resume.summary",
+            Section::Skills => "resume.skills",
+            Section::Projects => "resume.projects",
+            Section::Languages => "resume.languages",
+        }
+    }
+}
+
+pub fn load(root: &Path, errors: &mut Vec<String>) -> Option<ResumeConfig> {
+    content::parse::<ResumeConfig>(&root.join(CONFIG), errors)
+}
+
+pub fn check(content: &Content, errors: &mut Vec<String>) {
+    let resume = &content.resume;
+    if resume.sections.is_empty() {
+        errors.push(format!("{CONFIG}: list at least one section"));
+    }
+    for (i, section) in resume.sections.iter().enumerate() {
+        if resume.sections[..i].contains(section) {
+            errors.push(format!(
+                "{CONFIG}: section `{}` is listed twice",
+                section.title_key()
+            ));
+        }
+    }
+
+    let mut ids = HashSet::new();
+    for id in &resume.projects {
+        if !ids.insert(id) {
+            errors.push(format!("{CONFIG}: project `{id}` is listed twice"));
+        }
+        if !content.projects.iter().any(|p| &p.id == id && !p.pinned) {
+            errors.push(format!("{CONFIG}: unknown project id `{id}`"));
+        }
+    }
+    if resume.sections.contains(&Section::Projects) && resume.projects.is_empty() {
+        errors.push(format!(
+            "{CONFIG}: the projects section needs at least one project"
+        ));
+    }
+}
+
+/// `RESUME` for src/utils/resume_data.rs.
+pub fn resume_rs(resume: &ResumeConfig) -> String {
+    let mut out = String::from("// @generated by build/resume.rs from content/resume.toml\n\n");
+    let sections: Vec<&str> = resume.sections.iter().map(|s| s.rust_path()).collect();
+    let min = match resume.min_proficiency {
+        Some(p) => format!("Some({})", p.rust_path()),
+        None => "None".into(),
+    };
+    writeln!(
+        out,
+        "pub const RESUME: Resume = Resume {{
+    sections: &[{}],
+    projects: &{:?},
+    min_proficiency: {min},
+    pdf: {PDF_PATH:?},
+}};",
+        sections.join(", "),
+        resume.projects,
+    )
+    .unwrap();
+    out
+}
+
+// --- cv.pdf ---
+
+/// A4, in points.
+const PAGE_W: f32 = 595.0;
+const PAGE_H: f32 = 842.0;
+const MARGIN: f32 = 56.0;
+const TEXT_W: f32 = PAGE_W - 2.0 * MARGIN;
+/// Line height, in font sizes.
+const LEADING: f32 = 1.4;
+
+#[derive(Clone, Copy)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    const ALL: [Font; 2] = [Font::Regular, Font::Bold];
+
+    /// Resource name in each page's content.
+    fn name(self) -> Name<'static> {
+        match self {
+            Font::Regular => Name(b"F1"),
+            Font::Bold => Name(b"F2"),
+        }
+    }
+
+    fn base_font(self) -> Name<'static> {
+        match self {
+            Font::Regular => Name(b"Helvetica"),
+            Font::Bold => Name(b"Helvetica-Bold"),
+        }
+    }
+
+    /// Advance of `c` in thousandths of an em, from Adobe's metrics for the
+    /// two fonts. Outside ASCII only the punctuation the content uses is
+    /// exact; accented letters get a typical letter's width.
+    fn width(self, c: char) -> f32 {
+        let table = match self {
+            Font::Regular => &HELVETICA,
+            Font::Bold => &HELVETICA_BOLD,
+        };
+        let w = match c {
+            ' '..='~' => table[c as usize - ' ' as usize],
+            '‘' | '’' => [222, 278][self as usize],
+            '“' | '”' => [333, 500][self as usize],
+            '•' => 350,
+            '·' => 278,
+            '–' => 556,
+            '—' | '…' => 1000,
+            c if c.is_alphabetic() => [556, 611][self as usize],
+            _ => 556,
+        };
+        f32::from(w)
+    }
+
+    fn text_width(self, text: &str, size: f32) -> f32 {
+        text.chars().map(|c| self.width(c)).sum::<f32>() * size / 1000.0
+    }
+}
+
+/// Widths of ' ' through '~'.
+#[rustfmt::skip]
+const HELVETICA: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+#[rustfmt::skip]
+const HELVETICA_BOLD: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// `text` in WinAnsiEncoding, which is what the fonts are declared with.
+fn win_ansi(text: &str) -> io::Result<Vec<u8>> {
+    text.chars()
+        .map(|c| {
+            let byte = match c {
+                ' '..='~' | '\u{a0}'..='\u{ff}' => c as u32 as u8,
+                '€' => 0x80,
+                '…' => 0x85,
+                '‘' => 0x91,
+                '’' => 0x92,
+                '“' => 0x93,
+                '”' => 0x94,
+                '•' => 0x95,
+                '–' => 0x96,
+                '—' => 0x97,
+                _ => {
+                    return Err(io::Error::other(format!(
+                        "cv.pdf: Helvetica can't show `{c}` in `{text}`"
+                    )));
+                }
+            };
+            Ok(byte)
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+struct Style {
+    font: Font,
+    size: f32,
+    /// 0 is black.
+    gray: f32,
+}
+
+const NAME: Style = Style {
+    font: Font::Bold,
+    size: 22.0,
+    gray: 0.1,
+};
+const ROLE: Style = Style {
+    font: Font::Regular,
+    size: 12.0,
+    gray: 0.3,
+};
+const HEADING: Style = Style {
+    font: Font::Bold,
+    size: 11.0,
+    gray: 0.1,
+};
+const BODY: Style = Style {
+    font: Font::Regular,
+    size: 9.5,
+    gray: 0.2,
+};
+const STRONG: Style = Style {
+    font: Font::Bold,
+    ..BODY
+};
+const MUTED: Style = Style {
+    font: Font::Regular,
+    size: 8.5,
+    gray: 0.45,
+};
+
+/// Some text in one style, optionally a link.
+struct Span<'a> {
+    text: &'a str,
+    style: Style,
+    link: Option<&'a str>,
+}
+
+impl<'a> Span<'a> {
+    fn new(text: &'a str, style: Style) -> Self {
+        Self {
+            text,
+            style,
+            link: None,
+        }
+    }
+
+    fn link(text: &'a str, style: Style, url: &'a str) -> Self {
+        Self {
+            text,
+            style,
+            link: Some(url),
+        }
+    }
+}
+
+struct Page {
+    ops: Ops,
+    links: Vec<(Rect, String)>,
+}
+
+impl Page {
+    /// Draw `text` with its baseline at (`x`, `y`).
+    fn draw(
+        &mut self,
+        x: f32,
+        y: f32,
+        text: &str,
+        style: Style,
+        link: Option<&str>,
+    ) -> io::Result<()> {
+        let bytes = win_ansi(text)?;
+        self.ops
+            .set_fill_gray(style.gray)
+            .begin_text()
+            .set_font(style.font.name(), style.size)
+            .next_line(x, y)
+            .show(Str(&bytes))
+            .end_text();
+        if let Some(url) = link {
+            let width = style.font.text_width(text, style.size);
+            let rect = Rect::new(x, y - style.size * 0.25, x + width, y + style.size * 0.85);
+            self.links.push((rect, url.to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Fills pages top to bottom, starting a new one when the next line doesn't
+/// fit.
+struct Layout {
+    pages: Vec<Page>,
+    /// Baseline of the last line written.
+    y: f32,
+}
+
+impl Layout {
+    fn new() -> Self {
+        let mut layout = Self {
+            pages: Vec::new(),
+            y: 0.0,
+        };
+        layout.new_page();
+        layout
+    }
+
+    fn new_page(&mut self) {
+        self.pages.push(Page {
+            ops: Ops::new(),
+            links: Vec::new(),
+        });
+        self.y = PAGE_H - MARGIN;
+    }
+
+    fn page(&mut self) -> &mut Page {
+        self.pages.last_mut().expect("there's always a page")
+    }
+
+    /// Start a new page unless `height` more points fit on this one.
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.new_page();
+        }
+    }
+
+    fn gap(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    fn newline(&mut self, height: f32) {
+        self.reserve(height);
+        self.y -= height;
+    }
+
+    /// Draw `text` with its baseline at (`x`, `self.y`).
+    fn draw(&mut self, x: f32, text: &str, style: Style, link: Option<&str>) -> io::Result<()> {
+        let y = self.y;
+        self.page().draw(x, y, text, style, link)
+    }
+
+    /// Write `spans` as running text, wrapping at word boundaries.
+    fn flow(&mut self, spans: &[Span]) -> io::Result<()> {
+        let height = spans.iter().map(|s| s.style.size).fold(0.0, f32::max) * LEADING;
+        self.newline(height);
+        let mut x = 0.0;
+        for span in spans {
+            let Span { style, link, .. } = *span;
+            for word in span.text.split_inclusive(' ') {
+                let trimmed = word.trim_end_matches(' ');
+                let fits = x + style.font.text_width(trimmed, style.size) <= TEXT_W;
+                if !fits && x > 0.0 {
+                    self.newline(height);
+                    x = 0.0;
+                }
+                if !trimmed.is_empty() {
+                    self.draw(MARGIN + x, trimmed, style, link)?;
+                }
+                x += style.font.text_width(word, style.size);
+            }
+        }
+        Ok(())
+    }
+
+    /// Section title with a rule under it, kept on the same page as the
+    /// first few lines after it.
+    fn heading(&mut self, title: &str) -> io::Result<()> {
+        self.gap(12.0);
+        self.reserve(HEADING.size * LEADING + BODY.size * LEADING * 3.0);
+        self.flow(&[Span::new(&title.to_uppercase(), HEADING)])?;
+        let y = self.y - 5.0;
+        self.page()
+            .ops
+            .set_stroke_gray(0.75)
+            .set_line_width(0.6)
+            .move_to(MARGIN, y)
+            .line_to(PAGE_W - MARGIN, y)
+            .stroke();
+        self.gap(6.0);
+        Ok(())
+    }
+
+    /// Name and site address at the bottom of every page, with page numbers
+    /// once there's more than one.
+    fn footers(&mut self, left: &str, url: &str) -> io::Result<()> {
+        let count = self.pages.len();
+        let y = MARGIN / 2.0;
+        for (i, page) in self.pages.iter_mut().enumerate() {
+            page.draw(MARGIN, y, left, MUTED, Some(url))?;
+            if count > 1 {
+                let number = format!("{} / {count}", i + 1);
+                let x = PAGE_W - MARGIN - MUTED.font.text_width(&number, MUTED.size);
+                page.draw(x, y, &number, MUTED, None)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self, title: &str, author: &str) -> Vec<u8> {
+        let catalog = Ref::new(1);
+        let tree = Ref::new(2);
+        let info = Ref::new(3);
+        let fonts = [Ref::new(4), Ref::new(5)];
+        let mut next = 6;
+        let mut alloc = || {
+            next += 1;
+            Ref::new(next - 1)
+        };
+
+        let mut pdf = Pdf::new();
+        let ids: Vec<(Ref, Ref, Vec<Ref>)> = self
+            .pages
+            .iter()
+            .map(|p| (alloc(), alloc(), p.links.iter().map(|_| alloc()).collect()))
+            .collect();
+        pdf.catalog(catalog).pages(tree);
+        pdf.pages(tree)
+            .kids(ids.iter().map(|(page, ..)| *page))
+            .count(ids.len() as i32);
+
+        for (page, (page_id, content_id, link_ids)) in self.pages.into_iter().zip(ids) {
+            let mut writer = pdf.page(page_id);
+            writer
+                .media_box(Rect::new(0.0, 0.0, PAGE_W, PAGE_H))
+                .parent(tree)
+                .contents(content_id)
+                .annotations(link_ids.iter().copied());
+            let mut resources = writer.resources();
+            let mut names = resources.fonts();
+            for (font, id) in Font::ALL.iter().zip(fonts) {
+                names.pair(font.name(), id);
+            }
+            names.finish();
+            resources.finish();
+            writer.finish();
+
+            pdf.stream(content_id, &page.ops.finish());
+            for ((rect, url), id) in page.links.iter().zip(link_ids) {
+                let mut link = pdf.annotation(id);
+                link.subtype(AnnotationType::Link)
+                    .rect(*rect)
+                    .border(0.0, 0.0, 0.0, None);
+                link.action()
+                    .action_type(ActionType::Uri)
+                    .uri(Str(url.as_bytes()));
+            }
+        }
+
+        for (font, id) in Font::ALL.iter().zip(fonts) {
+            pdf.type1_font(id)
+                .base_font(font.base_font())
+                .encoding_predefined(Name(b"WinAnsiEncoding"));
+        }
+        pdf.document_info(info)
+            .title(TextStr(title))
+            .author(TextStr(author));
+        pdf.finish()
+    }
+}
+
+/// "github.com/araujoviana" for "https://github.com/araujoviana/"
+fn display_url(url: &str) -> &str {
+    og_images::host(url)
+        .trim_start_matches("www.")
+        .trim_end_matches('/')
+}
+
+/// cv.pdf, from the same data and in the same order as /resume.
+pub fn pdf(content: &Content) -> io::Result<Vec<u8>> {
+    let profile = &content.profile;
+    let site = &content.site;
+    let text = |key: &str| content.strings.text(0, key).unwrap_or_default();
+    let mut layout = Layout::new();
+
+    // header
+    layout.flow(&[Span::new(&profile.name, NAME)])?;
+    layout.flow(&[Span::new(profile.role.base(), ROLE)])?;
+    layout.gap(2.0);
+    layout.flow(&[Span::new(profile.headline.base(), BODY)])?;
+    let mailto = format!("mailto:{}", profile.email);
+    let home = site.url("/");
+    let mut contact = vec![Span::link(&profile.email, MUTED, &mailto)];
+    for s in &profile.socials {
+        contact.push(Span::new(" · ", MUTED));
+        contact.push(Span::link(display_url(&s.url), MUTED, &s.url));
+    }
+    contact.push(Span::new(" · ", MUTED));
+    contact.push(Span::link(display_url(&home), MUTED, &home));
+    if let Some(location) = &profile.location {
+        contact.push(Span::new(" · ", MUTED));
+        contact.push(Span::new(location.base(), MUTED));
+    }
+    layout.gap(2.0);
+    layout.flow(&contact)?;
+
+    let resume = &content.resume;
+    for section in &resume.sections {
+        layout.heading(text(section.title_key()))?;
+        match section {
+            Section::Summary => {
+                layout.flow(&[Span::new(text("about.p1"), BODY)])?;
+                layout.gap(4.0);
+                layout.flow(&[Span::new(text("about.p2"), BODY)])?;
+            }
+            Section::Skills => {
+                for g in &content.groups {
+                    let items: Vec<&str> = g
+                        .items
+                        .iter()
+                        .filter(|i| {
+                            resume
+                                .min_proficiency
+                                .is_none_or(|min| i.proficiency >= min)
+                        })
+                        .map(|i| i.name.as_str())
+                        .collect();
+                    if items.is_empty() {
+                        continue;
+                    }
+                    let title = format!("{}: ", g.title.base());
+                    layout.flow(&[
+                        Span::new(&title, STRONG),
+                        Span::new(&items.join(", "), BODY),
+                    ])?;
+                    layout.gap(2.0);
+                }
+            }
+            Section::Projects => {
+                for id in &resume.projects {
+                    let Some(p) = content.projects.iter().find(|p| &p.id == id) else {
+                        continue;
+                    };
+                    let details: Vec<&str> =
+                        [p.language_label.as_str(), p.date.as_deref().unwrap_or("")]
+                            .into_iter()
+                            .filter(|s| !s.is_empty())
+                            .collect();
+                    let details = format!("  {}", details.join(" · "));
+                    layout.reserve(BODY.size * LEADING * 3.0);
+                    layout.flow(&[Span::new(p.name.base(), STRONG), Span::new(&details, MUTED)])?;
+                    layout.flow(&[Span::new(p.description.base(), BODY)])?;
+                    layout.flow(&[Span::link(display_url(&p.repo_url), MUTED, &p.repo_url)])?;
+                    layout.gap(5.0);
+                }
+            }
+            Section::Languages => {
+                let languages: Vec<String> = profile
+                    .languages
+                    .iter()
+                    .map(|l| format!("{} ({})", l.name.base(), l.level.base()))
+                    .collect();
+                layout.flow(&[Span::new(&languages.join(" · "), BODY)])?;
+            }
+        }
+    }
+
+    let page_url = site.url(PAGE_PATH);
+    let footer = format!("{} · {}", profile.name, display_url(&page_url));
+    layout.footers(&footer, &page_url)?;
+
+    let title = format!("{} · {}", profile.name, text("resume.title"));
+    Ok(layout.finish(&title, &profile.name))
+}
@@ -0,0 +1,61 @@
+// Emits the compile-time part of the command palette index (projects, skills
+// and posts); src/utils/search.rs adds the sections and the About text.
+
+use std::fmt::Write as _;
+
+use crate::content::Content;
+use crate::i18n::Localized;
+
+pub fn index_rs(content: &Content) -> String {
+    let mut out = String::from("// @generated by build/search.rs from content/\n\n");
+
+    out.push_str("pub const CONTENT_ENTRIES: &[SearchEntry] = &[\n");
+    for p in content.projects.iter().filter(|p| !p.pinned) {
+        let mut words = vec![p.language_label.as_str()];
+        words.extend(p.tags.iter().map(String::as_str));
+        words.extend(p.skills.iter().map(String::as_str));
+        entry(&mut out, "Project", &p.name, &p.description, &words, &p.id);
+    }
+    for g in &content.groups {
+        for item in &g.items {
+            let mut words: Vec<&str> = g.subtitle.all().collect();
+            words.extend(item.note.iter().flat_map(Localized::all));
+            words.extend(
+                item.projects
+                    .iter()
+                    .filter_map(|id| content.projects.iter().find(|p| &p.id == id))
+                    .flat_map(|p| p.name.all()),
+            );
+            let name = Localized::same(&item.name);
+            entry(&mut out, "Skill", &name, &g.title, &words, &item.id);
+        }
+    }
+    for p in &content.posts {
+        let mut words: Vec<&str> = p.tags.iter().map(String::as_str).collect();
+        words.extend(p.toc.iter().map(|t| t.title.as_str()));
+        let (title, summary) = (Localized::same(&p.title), Localized::same(&p.summary));
+        entry(&mut out, "Post", &title, &summary, &words, &p.slug);
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// `target` is the project id, skill id or post slug, depending on `kind`.
+fn entry(
+    out: &mut String,
+    kind: &str,
+    title: &Localized,
+    detail: &Localized,
+    words: &[&str],
+    target: &str,
+) {
+    // lowercased here so matching only has to fold the query
+    let keywords = words.join(" ").to_lowercase();
+    writeln!(
+        out,
+        "    SearchEntry {{ title: {}, detail: {}, keywords: {keywords:?}, target: Target::{kind}({target:?}) }},",
+        title.rust(),
+        detail.rust(),
+    )
+    .unwrap();
+}
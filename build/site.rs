@@ -0,0 +1,147 @@
+// Site-wide settings from content/site.toml, and the files that are served
+// next to index.html rather than compiled in (feeds, ...). Those are written
+// to target/site-files/<target>/<profile>/, see `out_dir`; the Trunk
+// post_build hook (scripts/site_files.sh) copies them into dist/ and splices
+// head.html into index.html.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::content::{self, Content};
+use crate::feeds;
+use crate::media;
+use crate::og_images;
+use crate::pwa;
+use crate::resume;
+
+const CONFIG: &str = "content/site.toml";
+/// Overrides `base_url`, so each deploy can point at its own origin.
+pub const BASE_URL_ENV: &str = "SITE_URL";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SiteConfig {
+    /// Where dist/ is served from, without a trailing slash.
+    pub base_url: String,
+    pub title: String,
+    pub description: String,
+}
+
+impl SiteConfig {
+    /// Absolute URL of a site path like "/posts/x".
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// The path part of `base_url`: "/rusty-web-portfolio", or "" at the
+    /// root of a host.
+    pub fn base_path(&self) -> &str {
+        let after_scheme = self.base_url.find("://").map_or(0, |i| i + 3);
+        self.base_url[after_scheme..]
+            .find('/')
+            .map_or("", |i| &self.base_url[after_scheme + i..])
+    }
+}
+
+pub fn load(root: &Path, errors: &mut Vec<String>) -> Option<SiteConfig> {
+    let path = root.join(CONFIG);
+    let text = match fs::read_to_string(&path) {
+        Ok(t) => t,
+        Err(e) => {
+            errors.push(format!("{}: {e}", path.display()));
+            return None;
+        }
+    };
+    let mut site: SiteConfig = match toml::from_str(&text) {
+        Ok(s) => s,
+        Err(e) => {
+            errors.push(format!("{}: {e}", path.display()));
+            return None;
+        }
+    };
+
+    let mut from = CONFIG;
+    if let Ok(url) = env::var(BASE_URL_ENV)
+        && !url.is_empty()
+    {
+        site.base_url = url;
+        from = BASE_URL_ENV;
+    }
+    site.base_url
+        .truncate(site.base_url.trim_end_matches('/').len());
+    if !content::is_valid_url(&site.base_url) {
+        errors.push(format!("{from}: malformed base_url `{}`", site.base_url));
+    }
+    Some(site)
+}
+
+/// `SITE` for src/utils/site_data.rs.
+pub fn site_rs(site: &SiteConfig) -> String {
+    let mut out = String::from("// @generated by build/site.rs from content/site.toml\n\n");
+    writeln!(
+        out,
+        "pub const SITE: Site = Site {{
+    base_url: {:?},
+    base_path: {:?},
+    title: {:?},
+    description: {:?},
+    og_image: {:?},
+}};",
+        site.base_url,
+        site.base_path(),
+        site.title,
+        site.description,
+        og_images::SITE_PATH,
+    )
+    .unwrap();
+    out
+}
+
+/// Where the files go: a fixed path rather than OUT_DIR, whose hash the hook
+/// can't know. Keyed by target and profile so a host `cargo check` doesn't
+/// clobber the wasm build's files. Keep in sync with scripts/site_files.sh.
+pub fn out_dir(root: &Path) -> PathBuf {
+    // joining an absolute CARGO_TARGET_DIR replaces `root`
+    let target_dir = root.join(env::var_os("CARGO_TARGET_DIR").unwrap_or_else(|| "target".into()));
+    let var = |name| env::var(name).unwrap_or_default();
+    target_dir
+        .join("site-files")
+        .join(var("TARGET"))
+        .join(var("PROFILE"))
+}
+
+pub fn write(content: &Content, out_dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(out_dir)?;
+    fs::write(out_dir.join("feed.xml"), feeds::atom(content))?;
+    fs::write(out_dir.join("feed.json"), feeds::json(content))?;
+    fs::write(out_dir.join("head.html"), head_html(content))?;
+    og_images::write(content, &out_dir.join("og"))?;
+    media::write_posters(content, &out_dir.join("posters"))?;
+    fs::write(
+        out_dir.join(resume::PDF_PATH.trim_start_matches('/')),
+        resume::pdf(content)?,
+    )?;
+    pwa::write(content, out_dir)?;
+    Ok(())
+}
+
+/// Tags for index.html's <head> pointing at the files above.
+fn head_html(content: &Content) -> String {
+    let site = &content.site;
+    let title = feeds::escape(&site.title);
+    format!(
+        "    <link rel=\"alternate\" type=\"application/atom+xml\" title=\"{title}\" href=\"{}\" />\n    \
+         <link rel=\"alternate\" type=\"application/feed+json\" title=\"{title}\" href=\"{}\" />\n    \
+         <link rel=\"manifest\" href=\"{}\" />\n    \
+         <link rel=\"apple-touch-icon\" href=\"{}\" />\n",
+        site.url("/feed.xml"),
+        site.url("/feed.json"),
+        site.url(pwa::MANIFEST_PATH),
+        site.url(&pwa::icon_path(192)),
+    )
+}
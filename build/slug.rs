@@ -0,0 +1,14 @@
+// URL-safe ids made from names and headings, for the generated tables.
+
+/// "Vector search (FAISS)" -> "vector-search-faiss"
+pub fn slugify(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_alphanumeric() {
+            out.extend(c.to_lowercase());
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    out.trim_end_matches('-').to_string()
+}
@@ -0,0 +1,63 @@
+#!/usr/bin/env bash
+# Refreshes content/repo_stats.json from the GitHub API.
+#
+# The build never touches the network; it only embeds whatever snapshot is
+# checked in. Run this by hand (or in a scheduled job) and commit the result:
+#
+#   GITHUB_TOKEN=... scripts/fetch_repo_stats.sh
+#
+# Needs curl and jq. Projects whose repo_url isn't a github.com/<owner>/<repo>
+# link are skipped, and a repo that fails to fetch simply gets no entry.
+
+set -euo pipefail
+
+root="$(cd "$(dirname "$0")/.." && pwd)"
+projects="$root/content/projects.toml"
+out="$root/content/repo_stats.json"
+
+auth=()
+if [[ -n "${GITHUB_TOKEN:-}" ]]; then
+    auth=(-H "Authorization: Bearer $GITHUB_TOKEN")
+fi
+
+gh() {
+    curl -fsSL "${auth[@]}" -H "Accept: application/vnd.github+json" "https://api.github.com/$1"
+}
+
+# "id<TAB>repo_url" per project, in file order
+pairs=$(awk -F' = ' '
+    /^id = /       { gsub(/"/, "", $2); id = $2 }
+    /^repo_url = / { gsub(/"/, "", $2); print id "\t" $2 }
+' "$projects")
+
+repos='{}'
+while IFS=$'\t' read -r id url; do
+    slug=$(sed -nE 's#^https://github.com/([^/?#]+/[^/?#]+)/?$#\1#p' <<<"$url")
+    [[ -z "$slug" ]] && continue
+
+    echo "fetching $slug" >&2
+    if ! repo=$(gh "repos/$slug") \
+        || ! commits=$(gh "repos/$slug/commits?per_page=1") \
+        || ! langs=$(gh "repos/$slug/languages"); then
+        echo "  skipped $slug" >&2
+        continue
+    fi
+
+    entry=$(jq -n \
+        --argjson repo "$repo" \
+        --argjson commits "$commits" \
+        --argjson langs "$langs" '{
+            stars: $repo.stargazers_count,
+            last_commit: ($commits[0].commit.committer.date // $repo.pushed_at),
+            languages: ($langs | to_entries | sort_by(-.value) | map(.key) | .[:3]),
+            archived: $repo.archived
+        }')
+    repos=$(jq --arg id "$id" --argjson entry "$entry" '. + {($id): $entry}' <<<"$repos")
+done <<<"$pairs"
+
+jq -n \
+    --arg fetched_at "$(date -u +%Y-%m-%dT%H:%M:%SZ)" \
+    --argjson repos "$repos" \
+    '{fetched_at: $fetched_at, repos: $repos}' >"$out"
+
+echo "wrote $out" >&2
@@ -0,0 +1,16 @@
+#!/usr/bin/env sh
+# Writes a static page for every route into a dist/ that `trunk build` made:
+#
+#   trunk build --release && sh scripts/prerender.sh [dist]
+#
+# Builds the app natively with the `ssr` feature and runs it, which renders
+# each route into a copy of dist/index.html (see src/prerender.rs). The WASM
+# build hydrates those pages when they load. sitemap.xml and robots.txt get
+# their absolute URLs from content/site.toml or $SITE_URL, like the feeds.
+# Then it writes the service worker, whose precache list needs the pages.
+
+set -eu
+
+cd "$(dirname "$0")/.."
+cargo run --release --no-default-features --features ssr -- "${1:-dist}"
+bash scripts/service_worker.sh "${1:-dist}"
@@ -0,0 +1,56 @@
+#!/usr/bin/env bash
+# Writes <dist>/sw.js from scripts/sw.js, with the list of files to precache
+# taken from what is in <dist> once it's complete: Trunk's output, the files
+# from build/site.rs and the prerendered pages. scripts/prerender.sh runs it
+# last:
+#
+#   bash scripts/service_worker.sh <dist>
+#
+# The project videos and their GIF renditions are left out of the
+# list; the worker caches those when they're first played.
+
+set -euo pipefail
+
+dist="$1"
+template="$(cd "$(dirname "$0")" && pwd)/sw.js"
+cd "$dist"
+
+# NUL-separated, so no file name is split or globbed
+files() {
+    find . -type f "$@" -print0 | LC_ALL=C sort -z
+}
+# og/ is only ever fetched by link previews
+mapfile -d '' shell < <(files ! -path ./sw.js ! -path './og/*' \
+    ! -name '*.webm' ! -name '*.mp4' ! -name '*.gif')
+mapfile -d '' media < <(files \( -name '*.webm' -o -name '*.mp4' -o -name '*.gif' \))
+
+# cksum is in every POSIX shell environment, unlike sha256sum
+checksum() {
+    if [ $# -gt 0 ]; then
+        cat -- "$@" | cksum | cut -d ' ' -f 1
+    else
+        echo 0
+    fi
+}
+
+{
+    echo "// @generated by scripts/service_worker.sh from scripts/sw.js"
+    echo
+    echo "const VERSION = \"$(checksum "${shell[@]}")\";"
+    echo "const MEDIA_VERSION = \"$(checksum "${media[@]}")\";"
+    echo "const PRECACHE = ["
+    for f in "${shell[@]}"; do
+        f="${f#./}"
+        # pages are requested as their directory: ./, posts/x/
+        case "$f" in
+            index.html) f=./ ;;
+            */index.html) f="${f%index.html}" ;;
+        esac
+        # as a JS string literal
+        f="${f//\\/\\\\}"
+        echo "  \"${f//\"/\\\"}\","
+    done
+    echo "];"
+    echo
+    cat "$template"
+} > sw.js
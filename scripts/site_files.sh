@@ -0,0 +1,32 @@
+#!/usr/bin/env sh
+# Trunk post_build hook (see Trunk.toml).
+#
+# build/site.rs writes the files that are served next to index.html (feeds,
+# og/ preview images, cv.pdf, ...) to target/site-files/<target>/<profile>/
+# while cargo builds the app. This copies them into the staged dist/ and adds
+# head.html, their <link> tags, to index.html. The service worker is written
+# later, by scripts/prerender.sh, so it can precache the prerendered pages.
+
+set -eu
+
+dist="$TRUNK_STAGING_DIR"
+
+# the same path as site::out_dir in build/site.rs; a relative
+# CARGO_TARGET_DIR is relative to the crate root there too
+cd "$TRUNK_SOURCE_DIR"
+src="${CARGO_TARGET_DIR:-target}/site-files/wasm32-unknown-unknown/${TRUNK_PROFILE:-debug}"
+if [ ! -f "$src/head.html" ]; then
+    echo "site_files.sh: no build/site.rs output in $src" >&2
+    exit 1
+fi
+
+for f in "$src"/*; do
+    [ "$(basename "$f")" = head.html ] || cp -R "$f" "$dist/"
+done
+
+# insert head.html right before </head>
+awk -v head="$src/head.html" '
+    /<\/head>/ { while ((getline line < head) > 0) print line }
+    { print }
+' "$dist/index.html" > "$dist/index.html.tmp"
+mv "$dist/index.html.tmp" "$dist/index.html"
@@ -0,0 +1,144 @@
+// Service worker, so the site keeps working offline once it has been
+// visited. scripts/service_worker.sh writes dist/sw.js from this file once
+// scripts/prerender.sh has filled in dist/, putting these in front of it:
+//
+//   VERSION        checksum of everything in PRECACHE, so each deploy that
+//                  changes any of it gets caches of its own
+//   MEDIA_VERSION  the same for the project videos, which change far less
+//   PRECACHE       the app shell: index.html, the WASM, its JS glue, the CSS
+//                  and src/assets/, plus every prerendered page, relative to
+//                  this file
+//
+// Requests are answered:
+//   - page loads: network first, so a deploy shows up right away; offline,
+//     the last copy of that page, or else the main page, which renders any
+//     route itself
+//   - videos: from cache once they've been watched; the first time they come
+//     from the network and are stored in the background
+//   - everything else this site serves: cache first, stored on first use
+
+// Caches are per origin, and a GitHub Pages origin holds every project site
+// of its user, so names carry this worker's scope.
+const PREFIX = `portfolio ${self.registration.scope}`;
+const SHELL_CACHE = `${PREFIX} shell ${VERSION}`;
+const MEDIA_CACHE = `${PREFIX} media ${MEDIA_VERSION}`;
+const MAIN_PAGE = new URL("./", self.registration.scope).href;
+
+const isMedia = (url) => /\.(webm|mp4|gif)$/.test(url.pathname);
+
+// Whole-file media downloads under way, by URL, so the ranges a <video> asks
+// for while one runs don't each start another.
+const downloading = new Map();
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(SHELL_CACHE)
+      // `reload` skips the HTTP cache, which may still hold the last deploy
+      .then((cache) => cache.addAll(PRECACHE.map((path) => new Request(path, { cache: "reload" }))))
+      .then(() => self.skipWaiting()),
+  );
+});
+
+// Drop what older deploys of this site cached.
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((names) =>
+        Promise.all(
+          names
+            .filter((name) => name.startsWith(PREFIX) && name !== SHELL_CACHE && name !== MEDIA_CACHE)
+            .map((name) => caches.delete(name)),
+        ),
+      )
+      .then(() => self.clients.claim()),
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+  if (request.method !== "GET" || !request.url.startsWith(self.registration.scope)) {
+    return;
+  }
+
+  if (request.mode === "navigate") {
+    event.respondWith(page(request));
+  } else if (isMedia(url)) {
+    event.respondWith(media(request, event));
+  } else {
+    event.respondWith(asset(request));
+  }
+});
+
+async function page(request) {
+  const cache = await caches.open(SHELL_CACHE);
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      cache.put(request, response.clone());
+    }
+    return response;
+  } catch (offline) {
+    const cached = (await cache.match(request, { ignoreSearch: true })) || (await cache.match(MAIN_PAGE));
+    if (cached) {
+      return cached;
+    }
+    throw offline;
+  }
+}
+
+async function asset(request) {
+  const cache = await caches.open(SHELL_CACHE);
+  const cached = await cache.match(request);
+  if (cached) {
+    return cached;
+  }
+  const response = await fetch(request);
+  if (response.ok) {
+    cache.put(request, response.clone());
+  }
+  return response;
+}
+
+// <video> asks for byte ranges, which can't be cached as such: the whole file
+// is fetched once on the side, and ranges are cut from it from then on.
+async function media(request, event) {
+  const cache = await caches.open(MEDIA_CACHE);
+  const cached = await cache.match(request.url);
+  if (!cached) {
+    if (!downloading.has(request.url)) {
+      downloading.set(
+        request.url,
+        fetch(request.url)
+          .then((response) => response.ok && cache.put(request.url, response))
+          .finally(() => downloading.delete(request.url)),
+      );
+    }
+    event.waitUntil(downloading.get(request.url));
+    return fetch(request);
+  }
+
+  const range = /^bytes=(\d*)-(\d*)$/.exec(request.headers.get("Range") || "");
+  if (!range) {
+    return cached;
+  }
+  const body = await cached.blob();
+  const start = range[1] === "" ? Math.max(body.size - Number(range[2]), 0) : Number(range[1]);
+  const end = range[1] !== "" && range[2] !== "" ? Math.min(Number(range[2]), body.size - 1) : body.size - 1;
+  if (start > end) {
+    return new Response(null, {
+      status: 416,
+      headers: { "Content-Range": `bytes */${body.size}` },
+    });
+  }
+  return new Response(body.slice(start, end + 1), {
+    status: 206,
+    headers: {
+      "Content-Type": cached.headers.get("Content-Type") || "",
+      "Content-Range": `bytes ${start}-${end}/${body.size}`,
+      "Content-Length": String(end - start + 1),
+    },
+  });
+}
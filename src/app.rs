@@ -1,32 +1,196 @@
 use crate::{
-    components::{Background, CloudBg, Footer, NavBar},
-    pages::{About, Contact, Home, Projects, Skills},
+    components::{Background, ClientOnly, CloudBg, CommandPalette, Footer, NavBar},
+    pages::{
+        About, Contact, Home, NotFound, PostDetail, Posts, ProjectDetail, Projects, Resume, Skills,
+    },
+    routes,
+    utils::{
+        i18n::{I18n, Strings, use_i18n},
+        meta::{Head, PageMeta, use_page_meta},
+        project_filter::ProjectFilter,
+        projects_data::PROJECTS,
+        scroll, sections,
+        site_data::SITE,
+    },
 };
 
+use std::cell::Cell;
+use std::rc::Rc;
+
+use leptos::ev;
 use leptos::prelude::*;
+use leptos_router::{
+    NavigateOptions,
+    components::{Route, Router, Routes},
+    hooks::{use_navigate, use_params_map},
+};
 
 #[component]
 pub fn App() -> impl IntoView {
+    let i18n = I18n::new();
+    provide_context(i18n);
+    // after hydration, so a prerendered page is hydrated in its own language
+    Effect::new(move || i18n.detect());
+    Head::provide();
+
+    let palette_open = RwSignal::new(false);
+
     view! {
-        <div class="min-h-screen flex flex-col text-slate-100 relative ">
+        <Router base=SITE.base_path>
+            <div class="min-h-screen flex flex-col text-slate-100 relative ">
 
-            <Background />
+                <Background />
 
-            <CloudBg />
+                <ClientOnly>
+                    <CloudBg />
+                </ClientOnly>
 
-            // PAGE ROUTES
-            <div class="relative z-20 flex min-h-screen flex-col">
-                <NavBar />
-                <main class="flex-1">
-                    <Home />
-                    <Skills />
-                    <Projects />
-                    <Contact />
-                    <About />
+                // PAGE ROUTES
+                <div class="relative z-20 flex min-h-screen flex-col">
+                    <NavBar palette_open=palette_open />
+                    <main class="flex-1">
+                        <Routes fallback=NotFound>
+                            // in the order of routes::PAGES, which lists their pages
+                            <Route path=routes::RESUME view=Resume />
+                            <Route path=routes::MAIN_PAGE view=MainPage />
+                            <Route path=routes::PROJECT view=ProjectDetail />
+                            <Route path=routes::POST view=PostDetail />
+                        </Routes>
+                    </main>
+                    <Footer />
+                </div>
 
-                </main>
-                <Footer />
+                <CommandPalette open=palette_open />
             </div>
-        </div>
+        </Router>
+    }
+}
+
+/// The single-page portfolio itself, whichever section the URL names.
+#[component]
+fn MainPage() -> impl IntoView {
+    let params = use_params_map();
+    let section = Memo::new(move |_| sections::from_param(params.read().get("section").as_deref()));
+    // only a 404 <-> page switch re-renders; moving between sections doesn't
+    let found = Memo::new(move |_| section.get().is_some());
+
+    move || {
+        if found.get() {
+            view! { <Sections section=section /> }.into_any()
+        } else {
+            view! { <NotFound /> }.into_any()
+        }
+    }
+}
+
+/// Scrolls to the section in the URL, and keeps the URL on the section being
+/// read as the user scrolls (replacing, so back/forward only walks the
+/// sections they actually navigated to).
+#[component]
+fn Sections(section: Memo<Option<&'static str>>) -> impl IntoView {
+    // shared so Skills can jump to Projects filtered by a skill; the query
+    // string is only read once mounted, prerendered pages have none
+    let filter = RwSignal::new(ProjectFilter::default());
+    provide_context(filter);
+    Effect::new(move || filter.set(ProjectFilter::from_location(PROJECTS)));
+
+    let i18n = use_i18n();
+    use_page_meta(move || {
+        let id = section.get().unwrap_or(sections::SECTIONS[0]);
+        PageMeta {
+            title: section_title(i18n.t(), id).map(str::to_string),
+            ..PageMeta::site(sections::path(id))
+        }
+    });
+
+    // the section on screen, as far as the URL is concerned
+    let shown = StoredValue::new(None::<&'static str>);
+
+    Effect::new(move |prev: Option<()>| {
+        let Some(id) = section.get() else {
+            return;
+        };
+        if shown.get_value() == Some(id) {
+            return; // the URL caught up with the scroll position
+        }
+        shown.set_value(Some(id));
+
+        if prev.is_some() {
+            sections::scroll_to(id, true);
+        } else if scroll::take_skip_landing() {
+            // the command palette positions the page itself
+        } else if scroll::has_saved() {
+            // coming back from a sub-page lands where the user left off
+            scroll::restore();
+        } else {
+            // wait a frame so the sections have been laid out
+            request_animation_frame(move || sections::scroll_to(id, false));
+        }
+    });
+
+    let navigate = use_navigate();
+    let sync = move || {
+        // the page may have been left since the frame was requested
+        let Some(current) = shown.try_get_value() else {
+            return;
+        };
+        let Some(id) = sections::in_view() else {
+            return;
+        };
+        if current == Some(id) {
+            return;
+        }
+        shown.set_value(Some(id));
+
+        // keep the project filter's query string
+        let search = web_sys::window()
+            .and_then(|w| w.location().search().ok())
+            .unwrap_or_default();
+        navigate(
+            &format!("{}{search}", sections::href(id)),
+            NavigateOptions {
+                // already under the base path, which resolving would add again
+                resolve: false,
+                replace: true,
+                scroll: false,
+                ..Default::default()
+            },
+        );
+    };
+
+    // in_view() measures every section, so do it at most once a frame
+    let ticking = Rc::new(Cell::new(false));
+    let handle = window_event_listener(ev::scroll, move |_| {
+        if ticking.replace(true) {
+            return;
+        }
+        let ticking = ticking.clone();
+        let sync = sync.clone();
+        request_animation_frame(move || {
+            ticking.set(false);
+            sync();
+        });
+    });
+    on_cleanup(move || handle.remove());
+
+    view! {
+        <Home />
+        <Skills />
+        <Projects />
+        <Posts />
+        <Contact />
+        <About />
+    }
+}
+
+/// Page title for a section's route; the home page just has the site's.
+fn section_title(t: &Strings, id: &str) -> Option<&'static str> {
+    match id {
+        "skills" => Some(t.nav_skills),
+        "projects" => Some(t.nav_projects),
+        "posts" => Some(t.nav_posts),
+        "contact" => Some(t.nav_contact),
+        "about" => Some(t.nav_about),
+        _ => None,
     }
 }
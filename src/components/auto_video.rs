@@ -0,0 +1,69 @@
+use leptos::html;
+use leptos::prelude::*;
+
+use crate::utils::media::media_href;
+use crate::utils::projects_data::Media;
+
+/// A muted, looping recording. Browsers that can play none of its sources
+/// (Safari before 14.1 has no WebM) get its GIF or poster instead.
+#[component]
+pub fn AutoVideo(media: Media, class: &'static str) -> impl IntoView {
+    let vref = NodeRef::<html::Video>::new();
+    // prerendered as a <video>; the browser says whether that works
+    let playable = RwSignal::new(true);
+
+    let try_play = move || {
+        if let Some(v) = vref.get() {
+            v.set_muted(true); // IMPORTANT: property, not just attribute
+            let _ = v.play(); // Chromium may block; ignore error
+        }
+    };
+
+    Effect::new(move || {
+        let Some(v) = vref.get() else {
+            return;
+        };
+        if media
+            .sources()
+            .all(|(_, mime)| v.can_play_type(mime).is_empty())
+        {
+            playable.set(false);
+        } else {
+            try_play();
+        }
+    });
+
+    // first user gesture unblocks autoplay; drop the listener when the page changes
+    let handle = window_event_listener(leptos::ev::pointerdown, move |_| try_play());
+    on_cleanup(move || handle.remove());
+
+    let image = media.image();
+    view! {
+        <Show
+            when=move || playable.get() || image.is_none()
+            fallback=move || {
+                view! { <img class=class src=image.map(media_href) alt=media.label loading="lazy" /> }
+            }
+        >
+            <video
+                node_ref=vref
+                class=class
+                poster=media.poster.map(media_href)
+                aria-label=media.label
+                prop:muted=true
+                prop:autoplay=true
+                prop:loop=true
+                playsinline
+                preload="metadata"
+                on:loadedmetadata=move |_| try_play()
+            >
+                {media
+                    .sources()
+                    .map(|(src, mime)| view! { <source src=media_href(src) type=mime /> })
+                    .collect_view()}
+                // only shown where there's no <video> at all
+                {image.map(|src| view! { <img src=media_href(src) alt=media.label /> })}
+            </video>
+        </Show>
+    }
+}
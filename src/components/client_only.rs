@@ -0,0 +1,17 @@
+use leptos::prelude::*;
+
+/// Renders `children` only in the browser, once the page has hydrated;
+/// prerendered HTML (and hydration) get `fallback` instead. For pieces that
+/// draw or animate with browser APIs and have nothing useful to prerender.
+#[component]
+pub fn ClientOnly(#[prop(optional, into)] fallback: ViewFn, children: ChildrenFn) -> impl IntoView {
+    let mounted = RwSignal::new(false);
+    // effects don't run while prerendering, and run after hydration
+    Effect::new(move || mounted.set(true));
+
+    view! {
+        <Show when=move || mounted.get() fallback=fallback>
+            {children()}
+        </Show>
+    }
+}
@@ -1,16 +1,45 @@
 use leptos::prelude::*;
 
+use crate::components::ClientOnly;
+use crate::utils::media::svg_data_url;
 use crate::wasm::cloud_shader::init_hero;
+use crate::wasm::sky::{CloudTier, tier};
+
+const SIZE: &str = "w-[320px] h-[320px] sm:w-[380px] sm:h-[380px] md:w-[420px] md:h-[420px]";
+
+/// Drawn where there's no WebGL to raymarch the cloud with.
+const STATIC_CLOUD: &str = include_str!("../assets/cloud.svg");
 
 #[component]
 pub fn Cloud() -> impl IntoView {
-    Effect::new(|_| {
-        init_hero("hero-canvas").expect("cannot init hero");
-    });
+    // WebGL only once hydrated; until then an empty box holds the layout
+    view! {
+        <ClientOnly fallback=|| view! { <div class=SIZE></div> }>
+            <HeroCloud />
+        </ClientOnly>
+    }
+}
+
+#[component]
+fn HeroCloud() -> impl IntoView {
+    let tier = tier();
     view! {
-        <canvas
-            id="hero-canvas"
-            class="w-[320px] h-[320px] sm:w-[380px] sm:h-[380px] md:w-[420px] md:h-[420px]"
-        ></canvas>
+        <Show
+            when=move || tier.get() != CloudTier::Static
+            fallback=|| view! { <img class=SIZE src=svg_data_url(STATIC_CLOUD) alt="" /> }
+        >
+            <HeroCanvas />
+        </Show>
     }
 }
+
+#[component]
+fn HeroCanvas() -> impl IntoView {
+    Effect::new(|_| {
+        // an error is logged by the sky; the box just stays empty
+        if let Ok(cloud) = init_hero("hero-canvas") {
+            on_cleanup(move || cloud.stop());
+        }
+    });
+    view! { <canvas id="hero-canvas" class=SIZE></canvas> }
+}
@@ -1,10 +1,10 @@
-use std::cell::RefCell;
-use std::rc::Rc;
-
 use leptos::prelude::*;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::{JsCast, JsValue};
 
+use crate::wasm::cloud_shader::{CloudOptions, init_cloud_field};
+use crate::wasm::sky::{CloudTier, Frame, Layout, Placement, tier};
+
 // TWEAKS
 const CLOUD_COUNT_AREA_DIVISOR: f32 = 250_000.0;
 const PADDING_MULTIPLIER: f32 = 0.35;
@@ -12,9 +12,10 @@ const CLOUD_SIZE_BASE: f32 = 50.0;
 const CLOUD_SIZE_VARIATION: f32 = 180.0;
 const WIND_SPEED: f32 = 12.0;
 
+const CANVAS_ID: &str = "bg-clouds";
+
 #[derive(Clone, PartialEq)]
 struct BgCloud {
-    id: String,
     x_px: f32,
     y_px: f32,
     size: f32,
@@ -49,37 +50,9 @@ pub fn CloudBg() -> impl IntoView {
     let vw = RwSignal::new(0.0f32);
     let vh = RwSignal::new(0.0f32);
 
-    let scroll_y = RwSignal::new(0.0f32);
-
-    let time_s = RwSignal::new(0.0f32);
-
-    let enabled = move || vw.get() >= 768.0;
-
-    Effect::new(move || {
-        let window = web_sys::window().unwrap();
-        let perf = window.performance().unwrap();
-
-        let last_ms = RwSignal::new(perf.now() as f32);
-
-        let f = Rc::new(RefCell::new(None::<Closure<dyn FnMut(f64)>>));
-        let g = f.clone();
-
-        *g.borrow_mut() = Some(Closure::wrap(Box::new(move |ms: f64| {
-            let ms = ms as f32;
-            let prev = last_ms.get();
-            last_ms.set(ms);
-
-            let dt = ((ms - prev) / 1000.0).clamp(0.0, 0.05);
-            time_s.set(time_s.get() + dt);
-
-            let _ = web_sys::window()
-                .unwrap()
-                .request_animation_frame(f.borrow().as_ref().unwrap().as_ref().unchecked_ref());
-        }) as Box<dyn FnMut(f64)>));
-
-        let _ =
-            window.request_animation_frame(g.borrow().as_ref().unwrap().as_ref().unchecked_ref());
-    });
+    // without WebGL the background goes without clouds
+    let tier = tier();
+    let enabled = move || vw.get() >= 768.0 && tier.get() != CloudTier::Static;
 
     Effect::new(move || {
         let window = web_sys::window().unwrap();
@@ -87,15 +60,11 @@ pub fn CloudBg() -> impl IntoView {
         let update_size = Closure::wrap(Box::new(move || {
             let window = web_sys::window().unwrap();
 
-            if let Ok(w) = window.inner_width() {
-                if let Some(w) = w.as_f64() {
-                    vw.set(w as f32);
-                }
+            if let Some(w) = window.inner_width().ok().and_then(|w| w.as_f64()) {
+                vw.set(w as f32);
             }
-            if let Ok(h) = window.inner_height() {
-                if let Some(h) = h.as_f64() {
-                    vh.set(h as f32);
-                }
+            if let Some(h) = window.inner_height().ok().and_then(|h| h.as_f64()) {
+                vh.set(h as f32);
             }
         }) as Box<dyn FnMut()>);
 
@@ -109,20 +78,8 @@ pub fn CloudBg() -> impl IntoView {
             .add_event_listener_with_callback("resize", update_size.as_ref().unchecked_ref())
             .ok();
 
-        scroll_y.set(window.scroll_y().unwrap_or(0.0) as f32);
-
-        let on_scroll = Closure::wrap(Box::new(move || {
-            let window = web_sys::window().unwrap();
-            scroll_y.set(window.scroll_y().unwrap_or(0.0) as f32);
-        }) as Box<dyn FnMut()>);
-
-        window
-            .add_event_listener_with_callback("scroll", on_scroll.as_ref().unchecked_ref())
-            .ok();
-
         // CloudBg lives for the whole app lifetime; keep callbacks alive
         update_size.forget();
-        on_scroll.forget();
     });
 
     // spawn clouds once per viewport size
@@ -164,7 +121,6 @@ pub fn CloudBg() -> impl IntoView {
             }
 
             v.push(BgCloud {
-                id: format!("bg-cloud-{}", v.len()),
                 x_px: x,
                 y_px: y,
                 size,
@@ -179,69 +135,67 @@ pub fn CloudBg() -> impl IntoView {
     view! {
         // Above background (z-0), below content (z-20)
         <Show when=enabled fallback=|| ()>
-            <div class="fixed inset-0 z-10 pointer-events-none">
-                <For
-                    each=move || clouds.get()
-                    key=|c| c.id.clone()
-                    children=move |cloud| {
-                        let id = cloud.id.clone();
-                        let id_init = id.clone();
-                        let x = cloud.x_px;
-                        let y = cloud.y_px;
-                        let size = cloud.size;
-                        let depth = cloud.depth;
-                        let seed = cloud.seed;
-                        Effect::new(move || {
-                            let _ = crate::wasm::cloud_shader::init_cloud(
-                                &id_init,
-                                crate::wasm::cloud_shader::CLOUD_FRAGMENT_SHADER,
-                                crate::wasm::cloud_shader::CloudOptions {
-                                    render_scale: 0.45,
-                                    seed,
-                                    time_offset: seed * 10.0,
-                                    default_sun: Some([0.7, 0.8, 0.9]),
-                                    ..Default::default()
-                                },
-                            );
-                        });
-
-                        // init each canvas once
-
-                        view! {
-                            <canvas
-                                id=id
-                                class="absolute will-change-transform"
-
-                                style=move || {
-                                    let t = time_s.get();
-                                    let width = vw.get();
-                                    let padding = vh.get() * PADDING_MULTIPLIER;
-                                    let depth_factor = 0.35 + depth * 0.65;
-                                    let drift = t * WIND_SPEED * depth_factor;
-                                    let span = width + padding * 2.0 + size;
-                                    let mut u = (x + drift + padding + size) % span;
-                                    if u < 0.0 {
-                                        u += span;
-                                    }
-                                    let x_render = u - padding - size;
-                                    let op = 0.35 + depth * 0.45;
-                                    let strength = 1.0_f32;
-                                    let y_render = y - scroll_y.get() * strength;
-                                    format!(
-                                        "transform: translate3d({:.1}px,{:.1}px,0);
-                                    width:{:.1}px; height:{:.1}px; opacity:{:.3};",
-                                        x_render,
-                                        y_render,
-                                        size,
-                                        size,
-                                        op,
-                                    )
-                                }
-                            />
-                        }
-                    }
-                />
+            <div class="fixed inset-0 z-10 pointer-events-none print:hidden">
+                <CloudField clouds=clouds />
             </div>
         </Show>
     }
 }
+
+/// Every background cloud, in one canvas.
+#[component]
+fn CloudField(clouds: Memo<Vec<BgCloud>>) -> impl IntoView {
+    Effect::new(move |_| {
+        let field = init_cloud_field(
+            CANVAS_ID,
+            CloudOptions {
+                render_scale: 0.45,
+                default_sun: Some([0.7, 0.8, 0.9]),
+                ..Default::default()
+            },
+            drift(clouds),
+        );
+        if let Ok(field) = field {
+            on_cleanup(move || field.stop());
+        }
+    });
+    view! { <canvas id=CANVAS_ID class="absolute inset-0 w-full h-full"></canvas> }
+}
+
+/// The clouds blown across the viewport, wrapping around, and scrolled with
+/// the page.
+fn drift(clouds: Memo<Vec<BgCloud>>) -> Layout {
+    Box::new(move |frame: &Frame| {
+        let Some(clouds) = clouds.try_get_untracked() else {
+            return Vec::new();
+        };
+        let scroll_y = web_sys::window()
+            .and_then(|w| w.scroll_y().ok())
+            .unwrap_or(0.0) as f32;
+        let padding = frame.height * PADDING_MULTIPLIER;
+        let count = (clouds.len() as f32 * frame.quality.bg_clouds).ceil() as usize;
+
+        clouds
+            .iter()
+            .take(count)
+            .map(|cloud| {
+                let depth_factor = 0.35 + cloud.depth * 0.65;
+                let drift = frame.time * WIND_SPEED * depth_factor;
+                let span = frame.width + padding * 2.0 + cloud.size;
+                let mut u = (cloud.x_px + drift + padding + cloud.size) % span;
+                if u < 0.0 {
+                    u += span;
+                }
+                Placement {
+                    x: u - padding - cloud.size,
+                    y: cloud.y_px - scroll_y,
+                    width: cloud.size,
+                    height: cloud.size,
+                    opacity: 0.35 + cloud.depth * 0.45,
+                    seed: cloud.seed,
+                    time_offset: cloud.seed * 10.0,
+                }
+            })
+            .collect()
+    })
+}
@@ -0,0 +1,247 @@
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+use leptos_router::NavigateOptions;
+use leptos_router::hooks::use_navigate;
+use wasm_bindgen::JsCast;
+
+use crate::utils::glass::GLASS_TERMINAL;
+use crate::utils::i18n::use_i18n;
+use crate::utils::scroll;
+use crate::utils::search::{self, SearchEntry, Target};
+use crate::utils::sections;
+use crate::utils::site_data::SITE;
+
+const MAX_RESULTS: usize = 8;
+
+/// Going to a link from `SITE.href`, which is under the base path already.
+fn link() -> NavigateOptions {
+    NavigateOptions {
+        resolve: false,
+        ..Default::default()
+    }
+}
+
+/// Ctrl+K / "/" search over sections, projects, skills, posts and the About text.
+#[component]
+pub fn CommandPalette(open: RwSignal<bool>) -> impl IntoView {
+    let i18n = use_i18n();
+    let query = RwSignal::new(String::new());
+    let selected = RwSignal::new(0usize);
+    let input_ref = NodeRef::<html::Input>::new();
+
+    let results = Memo::new(move |_| query.with(|q| search::search(q, i18n.locale(), MAX_RESULTS)));
+
+    let handle = window_event_listener(ev::keydown, move |e| {
+        let key = e.key();
+        if (e.ctrl_key() || e.meta_key()) && key.eq_ignore_ascii_case("k") {
+            e.prevent_default();
+            open.update(|o| *o = !*o);
+        } else if key == "/" && !open.get_untracked() && !is_typing(&e) {
+            e.prevent_default();
+            open.set(true);
+        } else if key == "Escape" && open.get_untracked() {
+            open.set(false);
+        }
+    });
+    on_cleanup(move || handle.remove());
+
+    // fresh prompt on every open; focus once the input exists
+    Effect::new(move || {
+        if open.get() {
+            query.set(String::new());
+            selected.set(0);
+            request_animation_frame(move || {
+                if let Some(input) = input_ref.get_untracked() {
+                    let _ = input.focus();
+                }
+            });
+        }
+    });
+
+    let navigate = use_navigate();
+    let go = Callback::new(move |entry: SearchEntry| {
+        open.set(false);
+
+        match entry.target {
+            // posts have their own page, there's nothing to scroll to
+            Target::Post(slug) => {
+                navigate(&SITE.href(&format!("/posts/{slug}")), link());
+                return;
+            }
+            // sections have their own URL, and the main page scrolls to it
+            Target::Section(id) => {
+                scroll::forget();
+                navigate(
+                    &sections::href(id),
+                    NavigateOptions {
+                        scroll: false,
+                        ..link()
+                    },
+                );
+                return;
+            }
+            Target::Project(_) | Target::Skill(_) | Target::About(_) => {}
+        }
+
+        let on_main = web_sys::window()
+            .and_then(|w| w.location().pathname().ok())
+            .is_some_and(|p| sections::is_main_page(SITE.route(&p)));
+        if !on_main {
+            // the jump below decides where the main page lands
+            scroll::skip_landing();
+            navigate(
+                &sections::href(sections::SECTIONS[0]),
+                NavigateOptions {
+                    scroll: false,
+                    ..link()
+                },
+            );
+        }
+
+        // a filtered-out project card isn't rendered, so open its page instead
+        let navigate = navigate.clone();
+        scroll::reveal(entry.target.element_id(), move || {
+            if let Target::Project(id) = entry.target {
+                navigate(&SITE.href(&format!("/projects/{id}")), link());
+            }
+        });
+    });
+
+    let on_input_key = move |e: ev::KeyboardEvent| {
+        let count = results.with(Vec::len);
+        match e.key().as_str() {
+            "ArrowDown" if count > 0 => {
+                e.prevent_default();
+                selected.update(|i| *i = (*i + 1) % count);
+            }
+            "ArrowUp" if count > 0 => {
+                e.prevent_default();
+                selected.update(|i| *i = (*i + count - 1) % count);
+            }
+            "Enter" => {
+                if let Some(entry) = results.with(|r| r.get(selected.get_untracked()).copied()) {
+                    go.run(entry);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    view! {
+        <Show when=move || open.get() fallback=|| ()>
+            <div
+                class="fixed inset-0 z-[60] flex items-start justify-center bg-black/50 px-4 pt-[15vh]"
+                on:click=move |_| open.set(false)
+            >
+                <div
+                    class=format!("{GLASS_TERMINAL} w-full max-w-xl")
+                    role="dialog"
+                    aria-modal="true"
+                    aria-label=move || i18n.t().nav_search
+                    on:click=|e| e.stop_propagation()
+                >
+                    <div class="relative z-10">
+                        <input
+                            node_ref=input_ref
+                            type="text"
+                            placeholder=move || i18n.t().palette_placeholder
+                            aria-label=move || i18n.t().palette_label
+                            class="
+                            w-full bg-transparent
+                            px-5 py-4
+                            font-mono text-sm text-white/90
+                            placeholder:text-white/35
+                            border-b border-white/10
+                            outline-none
+                            "
+                            prop:value=move || query.get()
+                            on:input=move |e| {
+                                query.set(event_target_value(&e));
+                                selected.set(0);
+                            }
+                            on:keydown=on_input_key
+                        />
+
+                        <ul role="listbox" class="max-h-[50vh] overflow-y-auto py-2">
+                            {move || {
+                                results
+                                    .get()
+                                    .into_iter()
+                                    .enumerate()
+                                    .map(|(i, entry)| {
+                                        view! {
+                                            <PaletteResult
+                                                entry=entry
+                                                active=Signal::derive(move || selected.get() == i)
+                                                on_hover=Callback::new(move |_| selected.set(i))
+                                                on_pick=go
+                                            />
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </ul>
+
+                        <Show when=move || results.with(Vec::is_empty) fallback=|| ()>
+                            <p class="px-5 pb-4 font-mono text-xs text-white/45">
+                                {move || i18n.t().palette_no_matches}
+                            </p>
+                        </Show>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+fn PaletteResult(
+    entry: SearchEntry,
+    active: Signal<bool>,
+    on_hover: Callback<()>,
+    on_pick: Callback<SearchEntry>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <li role="option" aria-selected=move || active.get().to_string()>
+            <button
+                type="button"
+                class=move || {
+                    if active.get() {
+                        "w-full text-left px-5 py-2.5 bg-white/10"
+                    } else {
+                        "w-full text-left px-5 py-2.5"
+                    }
+                }
+                on:mouseenter=move |_| on_hover.run(())
+                on:click=move |_| on_pick.run(entry)
+            >
+                <span class="flex items-baseline justify-between gap-4">
+                    <span class="truncate text-sm text-white/85">
+                        {move || i18n.text(entry.title)}
+                    </span>
+                    <span class="shrink-0 font-mono text-[11px] text-white/40">
+                        {move || entry.target.label(i18n.t())}
+                    </span>
+                </span>
+                <Show when=move || !i18n.text(entry.detail).is_empty() fallback=|| ()>
+                    <span class="block truncate text-xs text-white/45">
+                        {move || i18n.text(entry.detail)}
+                    </span>
+                </Show>
+            </button>
+        </li>
+    }
+}
+
+/// Don't steal "/" from someone typing into a field.
+fn is_typing(e: &ev::KeyboardEvent) -> bool {
+    e.target()
+        .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+        .is_some_and(|el| {
+            matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                || el.is_content_editable()
+        })
+}
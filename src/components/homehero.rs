@@ -1,13 +1,15 @@
 use crate::components::Cloud;
 use crate::utils::glass::GLASS_HERO;
+use crate::utils::i18n::use_i18n;
+use crate::utils::profile_data::PROFILE;
+use crate::utils::resume_data::RESUME;
+use crate::utils::site_data::SITE;
 use leptos::prelude::*;
 
-const TITLE: &str = "Hi! I'm Matheus";
-const SUBTITLE: &str =
-    "I build reliable systems, cloud infrastructure, and low-level tools for real-world use.";
-
 #[component]
 pub fn HomeHero() -> impl IntoView {
+    let i18n = use_i18n();
+
     view! {
         <div class=format!("{GLASS_HERO} max-w-6xl mx-auto px-4 sm:px-6")>
             <section class="
@@ -30,18 +32,40 @@ pub fn HomeHero() -> impl IntoView {
                         font-semibold
                         tracking-tight
                         leading-tight
-                        ">{TITLE}</h1>
+                        ">{move || i18n.t().hero_title}</h1>
 
                         <p class="
                         max-w-md
                         text-lg
                         text-white/80
                         leading-relaxed
-                        ">{SUBTITLE}</p>
+                        ">{move || i18n.text(PROFILE.headline)}</p>
+
+                        <div class="flex flex-wrap gap-4 pt-2">
+                            <a
+                                href=SITE.href("/projects")
+                                data-noscroll="true"
+                                class="
+                                inline-flex items-center justify-center
+                                px-5 py-2.5
+                                rounded-xl
+                                text-white/80
+                                font-medium
+                                border border-white/15
+                                bg-white/5
+                                backdrop-blur
+                                transition duration-300
+                                hover:bg-white/10
+                                hover:border-white/25
+                                hover:text-white
+                                "
+                            >
+                                {move || i18n.t().hero_projects}
+                            </a>
 
-                        <div class="flex gap-4 pt-2">
                             <a
-                                href="#projects"
+                                href=SITE.href("/contact")
+                                data-noscroll="true"
                                 class="
                                 inline-flex items-center justify-center
                                 px-5 py-2.5
@@ -57,11 +81,14 @@ pub fn HomeHero() -> impl IntoView {
                                 hover:text-white
                                 "
                             >
-                                "Projects"
+                                {move || i18n.t().hero_contact}
                             </a>
 
+                            // rel=external: a file next to index.html, not a route
                             <a
-                                href="#contact"
+                                href=SITE.href(RESUME.pdf)
+                                rel="external"
+                                download
                                 class="
                                 inline-flex items-center justify-center
                                 px-5 py-2.5
@@ -77,7 +104,7 @@ pub fn HomeHero() -> impl IntoView {
                                 hover:text-white
                                 "
                             >
-                                "Contact"
+                                {move || i18n.t().hero_resume}
                             </a>
                         </div>
 
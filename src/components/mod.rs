@@ -6,3 +6,6 @@ export_comp!(homehero, HomeHero);
 export_comp!(cloud_bg, CloudBg);
 export_comp!(cloud, Cloud);
 export_comp!(background, Background);
+export_comp!(auto_video, AutoVideo);
+export_comp!(command_palette, CommandPalette);
+export_comp!(client_only, ClientOnly);
@@ -1,10 +1,14 @@
 use crate::utils::glass::{GLASS_NAV, NAV_LINK};
+use crate::utils::i18n::{Locale, Strings, use_i18n};
+use crate::utils::site_data::SITE;
 use leptos::prelude::*;
 
 #[component]
-pub fn NavBar() -> impl IntoView {
+pub fn NavBar(palette_open: RwSignal<bool>) -> impl IntoView {
+    let i18n = use_i18n();
+
     view! {
-        <nav class="fixed top-4 left-1/2 -translate-x-1/2 z-50 w-fit max-w-[92vw]">
+        <nav class="fixed top-4 left-1/2 -translate-x-1/2 z-50 w-fit max-w-[92vw] print:hidden">
             <div class=format!(
                 "{GLASS_NAV} flex items-center
          gap-2 sm:gap-6
@@ -14,21 +18,73 @@ pub fn NavBar() -> impl IntoView {
          overflow-x-auto sm:overflow-visible
          [scrollbar-width:none] [&::-webkit-scrollbar]:hidden",
             )>
-                <NavItem text="Home" href="#home" />
-                <NavItem text="Skills" href="#skills" />
-                <NavItem text="Projects" href="#projects" />
-                <NavItem text="Contact" href="#contact" />
-                <NavItem text="About" href="#about" />
+                <NavItem text=|t| t.nav_home href="/" />
+                <NavItem text=|t| t.nav_skills href="/skills" />
+                <NavItem text=|t| t.nav_projects href="/projects" />
+                <NavItem text=|t| t.nav_posts href="/posts" />
+                <NavItem text=|t| t.nav_contact href="/contact" />
+                <NavItem text=|t| t.nav_about href="/about" />
+                <button
+                    type="button"
+                    class=format!("{NAV_LINK} font-mono text-white/50")
+                    title=move || i18n.t().nav_search_hint
+                    aria-label=move || i18n.t().nav_search
+                    on:click=move |_| palette_open.set(true)
+                >
+                    "⌘K"
+                </button>
+                <LanguageSwitcher />
             </div>
         </nav>
     }
 }
 
 #[component]
-fn NavItem(text: &'static str, href: &'static str) -> impl IntoView {
+fn NavItem(text: fn(&Strings) -> &'static str, href: &'static str) -> impl IntoView {
+    let i18n = use_i18n();
+
+    // data-noscroll: the main page scrolls to the section itself
     view! {
-        <a href=href class=NAV_LINK>
-            {text}
+        <a href=SITE.href(href) data-noscroll="true" class=NAV_LINK>
+            {move || text(i18n.t())}
         </a>
     }
 }
+
+/// "EN / PT", the active one highlighted.
+#[component]
+fn LanguageSwitcher() -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div
+            role="group"
+            aria-label=move || i18n.t().nav_language
+            class="flex items-center gap-1 pl-1 font-mono text-xs"
+        >
+            {Locale::ALL
+                .into_iter()
+                .map(|locale| {
+                    let active = move || i18n.locale() == locale;
+                    view! {
+                        <button
+                            type="button"
+                            class=move || {
+                                if active() {
+                                    "px-1.5 py-1 text-white"
+                                } else {
+                                    "px-1.5 py-1 text-white/45 hover:text-white/80 transition"
+                                }
+                            }
+                            aria-pressed=move || active().to_string()
+                            lang=locale.code()
+                            on:click=move |_| i18n.set(locale)
+                        >
+                            {locale.code().to_uppercase()}
+                        </button>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
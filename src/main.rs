@@ -1,16 +1,35 @@
-use leptos::mount::mount_to_body;
+// the server-side view types of the main page nest deeper than the default
+#![recursion_limit = "256"]
 
 mod app;
 mod components;
 mod pages;
+mod prerender;
+mod routes;
 mod utils;
 mod wasm;
 
-use app::App;
-
+#[cfg(not(feature = "ssr"))]
 fn main() {
+    use app::App;
+    use leptos::mount::mount_to_body;
+
     // QoL tweak
     console_error_panic_hook::set_once();
+    utils::service_worker::register();
+
+    #[cfg(feature = "hydrate")]
+    if prerender::is_current_page() {
+        leptos::mount::hydrate_body(App);
+        return;
+    }
 
+    prerender::clear_body();
     mount_to_body(App);
 }
+
+/// Writes the static pages, see src/prerender.rs.
+#[cfg(feature = "ssr")]
+fn main() {
+    prerender::run();
+}
@@ -6,34 +6,14 @@ use std::time::Duration;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::closure::Closure;
 
+use crate::components::ClientOnly;
 use crate::utils::glass::GLASS_TERMINAL;
-
-const ABOUT_P1: &str = "I’m Matheus, a cloud and systems-focused intern who likes software that feels fast, reliable, and straight to the point. Most of my work lives around cloud infrastructure, Linux, automation, and the tooling that keeps systems running.";
-
-const ABOUT_P2: &str = "I learn by building. I’m good at debugging, picking up new tools quickly, and reading docs/specs when others won’t. I also lead when a team is stuck, and I’m comfortable talking with clients and teammates in both English and Portuguese.";
-
-const TERMINAL_SCRIPT: &[&str] = &[
-    "$ whoami",
-    "Matheus",
-    "",
-    "$ role",
-    "Solutions Architect Intern (Huawei Cloud)",
-    "",
-    "$ focus",
-    "performance / reliability / simple systems",
-    "",
-    "$ strengths",
-    "debugging / reading docs / guiding teams",
-    "",
-    "$ stack",
-    "Linux / Kubernetes / cloud infrastructure / Python / Rust",
-    "",
-    "$ languages",
-    "Portuguese (native) / English (advanced)",
-];
+use crate::utils::i18n::use_i18n;
+use crate::utils::profile_data::PROFILE;
 
 #[component]
 pub fn About() -> impl IntoView {
+    let i18n = use_i18n();
     let terminal_ref = NodeRef::<html::Div>::new();
     let is_visible = RwSignal::new(false);
 
@@ -81,17 +61,25 @@ pub fn About() -> impl IntoView {
                     // LEFT: About text
                     <div class="space-y-4">
                         <h2 class="text-3xl sm:text-4xl font-semibold tracking-tight text-white/90">
-                            "About"
+                            {move || i18n.t().about_title}
                         </h2>
 
-                        <p class="text-white/70 leading-relaxed max-w-prose">{ABOUT_P1}</p>
+                        <p id="about-p1" class="text-white/70 leading-relaxed max-w-prose">
+                            {move || i18n.t().about_p1}
+                        </p>
 
-                        <p class="text-white/60 leading-relaxed max-w-prose">{ABOUT_P2}</p>
+                        <p id="about-p2" class="text-white/60 leading-relaxed max-w-prose">
+                            {move || i18n.t().about_p2}
+                        </p>
                     </div>
 
                     // RIGHT: Terminal
                     <div class="lg:pt-2 [content-visibility:auto] [contain-intrinsic-size:1px_900px]">
-                        <div node_ref=terminal_ref class=format!("{GLASS_TERMINAL} p-5 sm:p-7")>
+                        <div
+                            id="about-terminal"
+                            node_ref=terminal_ref
+                            class=format!("{GLASS_TERMINAL} p-5 sm:p-7")
+                        >
                             // Faux window header
                             <div class="relative z-10 flex items-center justify-between pb-4">
                                 <div class="flex items-center gap-2">
@@ -99,10 +87,17 @@ pub fn About() -> impl IntoView {
                                     <span class="h-2.5 w-2.5 rounded-full bg-yellow-300/70"></span>
                                     <span class="h-2.5 w-2.5 rounded-full bg-green-400/70"></span>
                                 </div>
-                                <div class="text-xs text-white/40 font-mono">"matheus@sky: ~"</div>
+                                <div class="text-xs text-white/40 font-mono">
+                                    {format!("{}@sky: ~", PROFILE.first_name.to_lowercase())}
+                                </div>
                             </div>
 
-                            <TypingTerminal active=is_visible.read_only() />
+                            // prerendered as the whole script, typed out once hydrated
+                            <ClientOnly fallback=move || {
+                                view! { <pre class=TERMINAL_TEXT>{i18n.t().about_terminal.join("\n")}</pre> }
+                            }>
+                                <TypingTerminal active=is_visible.read_only() />
+                            </ClientOnly>
 
                             // Subtle bottom fade
                             <div class="pointer-events-none absolute inset-x-0 bottom-0 h-10 bg-gradient-to-t from-black/30 to-transparent"></div>
@@ -115,8 +110,15 @@ pub fn About() -> impl IntoView {
     }
 }
 
+const TERMINAL_TEXT: &str = "relative z-10 font-mono text-[13px] sm:text-sm leading-relaxed text-white/75 whitespace-pre-wrap";
+
 #[component]
 fn TypingTerminal(active: ReadSignal<bool>) -> impl IntoView {
+    let i18n = use_i18n();
+    // every locale's script has the same number of lines, so switching
+    // language mid-animation just carries on in the new one
+    let script = move || i18n.t().about_terminal;
+
     let line_idx = RwSignal::new(0usize);
     let char_idx = RwSignal::new(0usize);
 
@@ -128,18 +130,16 @@ fn TypingTerminal(active: ReadSignal<bool>) -> impl IntoView {
         let li = line_idx.get();
         let ci = char_idx.get();
 
-        if li >= TERMINAL_SCRIPT.len() {
+        let Some(line) = script().get(li).copied() else {
             return;
-        }
-
-        let line = TERMINAL_SCRIPT[li];
+        };
 
         let ms = if line.starts_with('$') { 280 } else { 38 };
         let delay = Duration::from_millis(ms);
 
         set_timeout(
             move || {
-                if ci < line.len() {
+                if ci < line.chars().count() {
                     char_idx.set(ci + 1);
                 } else {
                     char_idx.set(0);
@@ -151,24 +151,18 @@ fn TypingTerminal(active: ReadSignal<bool>) -> impl IntoView {
     });
 
     view! {
-        <pre class="
-        relative z-10
-        font-mono
-        text-[13px] sm:text-sm
-        leading-relaxed
-        text-white/75
-        whitespace-pre-wrap
-        ">
+        <pre class=TERMINAL_TEXT>
             {move || {
                 let mut out = String::new();
                 let li = line_idx.get();
                 let ci = char_idx.get();
-                for (i, line) in TERMINAL_SCRIPT.iter().enumerate() {
+                for (i, line) in script().iter().enumerate() {
                     if i < li {
                         out.push_str(line);
                         out.push('\n');
                     } else if i == li {
-                        out.push_str(&line[..ci.min(line.len())]);
+                        // by char, the Portuguese script isn't ASCII
+                        out.extend(line.chars().take(ci));
                         break;
                     }
                 }
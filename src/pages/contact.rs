@@ -1,4 +1,9 @@
 use crate::utils::glass::*;
+use crate::utils::i18n::use_i18n;
+use crate::utils::media::svg_data_url;
+use crate::utils::profile_data::PROFILE;
+use crate::utils::resume_data::RESUME;
+use crate::utils::site_data::SITE;
 use leptos::prelude::*;
 
 // REVIEW this could be useless?
@@ -31,11 +36,8 @@ mod clipboard {
 
 #[component]
 pub fn Contact() -> impl IntoView {
-    let email: &'static str = "matheusaraujo1@proton.me";
-    let github: &'static str = "https://github.com/araujoviana";
-    let linkedin: &'static str = "https://www.linkedin.com/in/matheus-g-viana/";
-
-    let mailto = format!("mailto:{email}");
+    let i18n = use_i18n();
+    let email = PROFILE.email;
 
     let (copied, set_copied) = signal(false);
 
@@ -54,10 +56,10 @@ pub fn Contact() -> impl IntoView {
                 <div class="mb-10 flex items-end justify-between gap-6">
                     <div class="space-y-2">
                         <h2 class="text-3xl sm:text-4xl font-semibold tracking-tight text-white/90">
-                            "Contact"
+                            {move || i18n.t().contact_title}
                         </h2>
                         <p class="text-white/60 max-w-prose leading-relaxed">
-                            "Ways to get in touch."
+                            {move || i18n.t().contact_subtitle}
                         </p>
                     </div>
                     <div class="hidden sm:block text-xs font-mono text-white/40">"$ links"</div>
@@ -97,66 +99,79 @@ pub fn Contact() -> impl IntoView {
                                         <span class="font-mono text-white/55">"email: "</span>
                                         <span class="select-all">{email}</span>
                                     </div>
+                                    {PROFILE
+                                        .location
+                                        .map(|location| {
+                                            view! {
+                                                <div class="mt-1 text-sm text-white/65">
+                                                    <span class="font-mono text-white/55">
+                                                        "location: "
+                                                    </span>
+                                                    {move || i18n.text(location)}
+                                                </div>
+                                            }
+                                        })}
                                 </div>
                             </div>
 
                             <div class="flex flex-wrap items-center gap-3">
-                                <a class=BTN_PRIMARY href=mailto>
-                                    "Email"
+                                <a class=BTN_PRIMARY href=PROFILE.mailto()>
+                                    {move || i18n.t().contact_email}
                                 </a>
                                 <button type="button" class=BTN_GHOST on:click=on_copy>
                                     <Show
                                         when=move || copied.get()
-                                        fallback=|| view! { "Copy email" }
+                                        fallback=move || i18n.t().contact_copy_email
                                     >
-                                        "Copied ✓"
+                                        {move || i18n.t().contact_copied}
                                     </Show>
                                 </button>
+                                <a class=BTN_GHOST href=SITE.href("/resume")>
+                                    {move || i18n.t().contact_resume}
+                                </a>
+                                <a class=BTN_GHOST href=SITE.href(RESUME.pdf) rel="external" download>
+                                    {move || i18n.t().contact_download_cv}
+                                </a>
                             </div>
                         </div>
 
                         <div class="grid grid-cols-1 sm:grid-cols-2 gap-4">
-                            <a
-                                class=SOCIAL_TILE
-                                href=github
-                                target="_blank"
-                                rel="noopener noreferrer"
-                            >
-                                <div class="flex items-center justify-between gap-3">
-                                    <div class="min-w-0">
-                                        <div class="text-sm font-semibold text-white/90">
-                                            "GitHub"
-                                        </div>
-                                        <div class="mt-1 text-xs text-white/50 font-mono truncate">
-                                            {github}
-                                        </div>
-                                    </div>
-                                    <span class="text-white/45 group-hover:text-white/70 transition">
-                                        "↗"
-                                    </span>
-                                </div>
-                            </a>
-
-                            <a
-                                class=SOCIAL_TILE
-                                href=linkedin
-                                target="_blank"
-                                rel="noopener noreferrer"
-                            >
-                                <div class="flex items-center justify-between gap-3">
-                                    <div class="min-w-0">
-                                        <div class="text-sm font-semibold text-white/90">
-                                            "LinkedIn"
-                                        </div>
-                                        <div class="mt-1 text-xs text-white/50 font-mono truncate">
-                                            {linkedin}
-                                        </div>
-                                    </div>
-                                    <span class="text-white/45 group-hover:text-white/70 transition">
-                                        "↗"
-                                    </span>
-                                </div>
-                            </a>
+                            {PROFILE
+                                .socials
+                                .iter()
+                                .map(|social| {
+                                    view! {
+                                        <a
+                                            class=SOCIAL_TILE
+                                            href=social.url
+                                            target="_blank"
+                                            rel="noopener noreferrer"
+                                        >
+                                            <div class="flex items-center justify-between gap-3">
+                                                <div class="flex items-center gap-3 min-w-0">
+                                                    <img
+                                                        src=svg_data_url(social.icon_svg)
+                                                        class="w-6 h-6 shrink-0"
+                                                        alt=""
+                                                        aria-hidden="true"
+                                                    />
+                                                    <div class="min-w-0">
+                                                        <div class="text-sm font-semibold text-white/90">
+                                                            {social.label}
+                                                        </div>
+                                                        <div class="mt-1 text-xs text-white/50 font-mono truncate">
+                                                            {social.url}
+                                                        </div>
+                                                    </div>
+                                                </div>
+                                                <span class="text-white/45 group-hover:text-white/70 transition">
+                                                    "↗"
+                                                </span>
+                                            </div>
+                                        </a>
+                                    }
+                                })
+                                .collect_view()}
                         </div>
 
                     </div>
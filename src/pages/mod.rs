@@ -5,3 +5,8 @@ export_comp!(about, About);
 export_comp!(contact, Contact);
 export_comp!(projects, Projects);
 export_comp!(skills, Skills);
+export_comp!(project_detail, ProjectDetail);
+export_comp!(posts, Posts);
+export_comp!(post_detail, PostDetail);
+export_comp!(resume, Resume);
+export_comp!(not_found, NotFound);
@@ -0,0 +1,50 @@
+use leptos::prelude::*;
+
+use crate::utils::glass::{BTN_PRIMARY, GLASS_TERMINAL};
+use crate::utils::i18n::use_i18n;
+use crate::utils::meta::{PageMeta, use_page_meta};
+use crate::utils::site_data::SITE;
+
+#[component]
+pub fn NotFound() -> impl IntoView {
+    let i18n = use_i18n();
+    use_page_meta(|| PageMeta {
+        title: Some("404".into()),
+        path: None,
+        json_ld: None,
+        ..PageMeta::site("/404")
+    });
+    // the prerendered 404.html is served for every unknown path, so the
+    // path is only known once mounted
+    let path = RwSignal::new(String::new());
+    Effect::new(move || {
+        if let Some(p) = web_sys::window().and_then(|w| w.location().pathname().ok()) {
+            path.set(p);
+        }
+    });
+
+    view! {
+        <section class="px-4 sm:px-6 lg:px-8 pt-28 sm:pt-32 pb-20">
+            <div class="mx-auto max-w-2xl">
+                <div class=format!("{GLASS_TERMINAL} p-6 sm:p-8")>
+                    <pre class="relative z-10 font-mono text-sm text-white/75 whitespace-pre-wrap">
+                        {move || {
+                            path.with(|path| {
+                                if path.is_empty() {
+                                    "404".to_string()
+                                } else {
+                                    format!("$ cd {path}\ncd: no such file or directory: {path}\n\n404")
+                                }
+                            })
+                        }}
+                    </pre>
+                </div>
+                <div class="mt-8">
+                    <a class=BTN_PRIMARY href=SITE.href("/")>
+                        {move || i18n.t().not_found_back_home}
+                    </a>
+                </div>
+            </div>
+        </section>
+    }
+}
@@ -0,0 +1,103 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+
+use crate::pages::NotFound;
+use crate::pages::posts::PostMeta;
+use crate::utils::glass::GLASS_CARD;
+use crate::utils::i18n::use_i18n;
+use crate::utils::json_ld;
+use crate::utils::meta::{PageMeta, use_page_meta};
+use crate::utils::posts_data::{Post, find_post};
+use crate::utils::site_data::SITE;
+
+#[component]
+pub fn PostDetail() -> impl IntoView {
+    let params = use_params_map();
+    let post = move || params.read().get("slug").and_then(|slug| find_post(&slug));
+
+    move || match post() {
+        Some(p) => view! { <PostView p=*p /> }.into_any(),
+        None => view! { <NotFound /> }.into_any(),
+    }
+}
+
+#[component]
+fn PostView(p: Post) -> impl IntoView {
+    use_page_meta(move || PageMeta {
+        title: Some(p.title.to_string()),
+        description: p.summary.to_string(),
+        kind: "article",
+        json_ld: json_ld::post(p.slug),
+        ..PageMeta::site(format!("/posts/{}", p.slug))
+    });
+
+    view! {
+        <section class="px-4 sm:px-6 lg:px-8 pt-28 sm:pt-32">
+            <div class="mx-auto max-w-3xl">
+                // data-noscroll: the main page restores its own offset, or
+                // scrolls to the section on a deep link
+                <a
+                    href=SITE.href("/posts")
+                    data-noscroll="true"
+                    class="text-sm font-mono text-white/50 hover:text-white/85 transition"
+                >
+                    "← cd ../posts"
+                </a>
+
+                <header class="mt-6 mb-10 space-y-3">
+                    <h1 class="text-4xl sm:text-5xl font-semibold tracking-tight text-white/90">
+                        {p.title}
+                    </h1>
+                    <PostMeta p=p />
+                    <div class="flex flex-wrap gap-3 text-xs font-mono text-white/45">
+                        {p.tags.iter().map(|t| view! { <span>{format!("#{t}")}</span> }).collect_view()}
+                    </div>
+                </header>
+
+                <Show when=move || !p.toc.is_empty() fallback=|| ()>
+                    <TableOfContents p=p />
+                </Show>
+
+                // rendered from trusted Markdown in content/posts/ at build time
+                <article class=format!("{GLASS_CARD} p-6 sm:p-8")>
+                    <div class="post-body relative z-10" inner_html=p.html></div>
+                </article>
+            </div>
+        </section>
+    }
+}
+
+#[component]
+fn TableOfContents(p: Post) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <nav
+            aria-label=move || i18n.t().posts_toc
+            class=format!("{GLASS_CARD} mb-8 p-6")
+        >
+            <div class="relative z-10">
+                <h2 class="mb-3 text-sm font-mono text-white/50">{move || i18n.t().posts_toc}</h2>
+                <ol class="space-y-1.5 text-sm">
+                    {p
+                        .toc
+                        .iter()
+                        .map(|entry| {
+                            let indent = if entry.level > 2 { "pl-4" } else { "" };
+                            view! {
+                                <li class=indent>
+                                    <a
+                                        href=format!("#{}", entry.id)
+                                        class="text-white/65 hover:text-white transition"
+                                    >
+                                        {entry.title}
+                                    </a>
+                                </li>
+                            }
+                        })
+                        .collect_view()}
+                </ol>
+            </div>
+        </nav>
+    }
+}
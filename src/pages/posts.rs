@@ -0,0 +1,83 @@
+use leptos::prelude::*;
+
+use crate::utils::glass::GLASS_CARD;
+use crate::utils::i18n::{fill, use_i18n};
+use crate::utils::posts_data::{POSTS, Post};
+use crate::utils::scroll;
+use crate::utils::site_data::SITE;
+
+#[component]
+pub fn Posts() -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <section id="posts" class="px-4 sm:px-6 lg:px-8 pt-16 sm:pt-20">
+            <div class="mx-auto max-w-6xl">
+                <div class="mb-10 flex items-end justify-between gap-6">
+                    <div class="space-y-2">
+                        <h2 class="text-3xl sm:text-4xl font-semibold tracking-tight text-white/90">
+                            {move || i18n.t().posts_title}
+                        </h2>
+                        <p class="text-white/60 max-w-prose leading-relaxed">
+                            {move || i18n.t().posts_subtitle}
+                        </p>
+                    </div>
+                    <div class="hidden sm:block text-xs font-mono text-white/40">
+                        "$ ls -t posts/"
+                    </div>
+                </div>
+
+                <div class="grid gap-6 md:grid-cols-2">
+                    {POSTS.iter().map(|p| view! { <PostCard p=*p /> }).collect_view()}
+                </div>
+            </div>
+        </section>
+    }
+}
+
+#[component]
+fn PostCard(p: Post) -> impl IntoView {
+    view! {
+        <article id=format!("post-{}", p.slug) class=format!("{GLASS_CARD} p-6")>
+            <div class="relative z-10 space-y-3">
+                <PostMeta p=p />
+                <h3 class="text-xl font-semibold tracking-tight text-white/90">
+                    // the router intercepts this, no full reload
+                    <a
+                        href=SITE.href(&format!("/posts/{}", p.slug))
+                        on:click=|_| scroll::remember()
+                        class="hover:text-white transition"
+                    >
+                        {p.title}
+                    </a>
+                </h3>
+                <p class="text-sm text-white/60 leading-relaxed">{p.summary}</p>
+                <div class="flex flex-wrap gap-3 text-xs font-mono text-white/40">
+                    {p.tags.iter().map(|t| view! { <span>{format!("#{t}")}</span> }).collect_view()}
+                </div>
+            </div>
+        </article>
+    }
+}
+
+/// "18 Oct 2026 · 4 min read"
+#[component]
+pub fn PostMeta(p: Post) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let line = move || {
+        let t = i18n.t();
+        let minutes = p.reading_minutes.to_string();
+        format!(
+            "{} · {}",
+            p.display_date(t.date_months),
+            fill(t.posts_reading_time, &[("n", &minutes)])
+        )
+    };
+
+    view! {
+        <p class="text-xs font-mono text-white/45">
+            <time datetime=p.date>{line}</time>
+        </p>
+    }
+}
@@ -0,0 +1,226 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+
+use crate::components::AutoVideo;
+use crate::pages::NotFound;
+use crate::utils::glass::{BTN_GHOST, BTN_PRIMARY, GLASS_CARD};
+use crate::utils::i18n::{Strings, fill, use_i18n};
+use crate::utils::json_ld;
+use crate::utils::media::{media_href, site_path, svg_data_url};
+use crate::utils::meta::{PageMeta, use_page_meta};
+use crate::utils::projects_data::{CaseStudy, Project, find_project};
+use crate::utils::site_data::SITE;
+
+#[component]
+pub fn ProjectDetail() -> impl IntoView {
+    let params = use_params_map();
+    let project = move || params.read().get("id").and_then(|id| find_project(&id));
+
+    move || match project() {
+        Some(p) => view! { <CaseStudyView p=*p /> }.into_any(),
+        None => view! { <NotFound /> }.into_any(),
+    }
+}
+
+#[component]
+fn CaseStudyView(p: Project) -> impl IntoView {
+    let i18n = use_i18n();
+    use_page_meta(move || PageMeta {
+        title: Some(i18n.text(p.name).to_string()),
+        description: i18n.text(p.description).to_string(),
+        image: p.og_image.map(str::to_string),
+        video: p.media.sources().next().map(|(src, _)| site_path(src)),
+        json_ld: json_ld::project(p.id),
+        ..PageMeta::site(format!("/projects/{}", p.id))
+    });
+
+    view! {
+        <section class="px-4 sm:px-6 lg:px-8 pt-28 sm:pt-32">
+            <div class="mx-auto max-w-4xl">
+                // data-noscroll: the main page restores its own offset, or
+                // scrolls to the section on a deep link
+                <a
+                    href=SITE.href("/projects")
+                    data-noscroll="true"
+                    class="text-sm font-mono text-white/50 hover:text-white/85 transition"
+                >
+                    "← cd ../projects"
+                </a>
+
+                <div class="mt-6 mb-10 space-y-3">
+                    <div class="flex flex-wrap items-center gap-3">
+                        <h1 class="text-4xl sm:text-5xl font-semibold tracking-tight text-white/90">
+                            {move || i18n.text(p.name)}
+                        </h1>
+                        {p
+                            .language_svg
+                            .map(|svg| {
+                                view! {
+                                    <img
+                                        class="w-7 h-7 shrink-0"
+                                        src=svg_data_url(svg)
+                                        alt=""
+                                        aria-hidden="true"
+                                    />
+                                }
+                            })}
+                    </div>
+                    <p class="text-white/65 leading-relaxed max-w-prose">
+                        {move || i18n.text(p.description)}
+                    </p>
+                    <div class="flex flex-wrap gap-3 text-xs font-mono text-white/45">
+                        <span>{p.language_label}</span>
+                        {p
+                            .status
+                            .map(|s| view! { <span>{move || s.label(i18n.t()).to_lowercase()}</span> })}
+                        {p
+                            .date
+                            .map(|d| {
+                                view! {
+                                    <span>{move || fill(i18n.t().case_study_since, &[("date", d)])}</span>
+                                }
+                            })}
+                        {p.tags.iter().map(|t| view! { <span>{format!("#{}", t.to_lowercase())}</span> }).collect_view()}
+                    </div>
+                </div>
+
+                {match p.case_study {
+                    Some(cs) => view! { <CaseStudyBody cs=*cs /> }.into_any(),
+                    None => {
+                        view! {
+                            <div class=format!("{GLASS_CARD} p-6 sm:p-8")>
+                                <p class="relative z-10 text-white/60">
+                                    {move || i18n.t().case_study_no_write_up}
+                                </p>
+                            </div>
+                        }
+                            .into_any()
+                    }
+                }}
+
+                <div class="mt-10 flex flex-wrap gap-3">
+                    <a class=BTN_PRIMARY href=p.repo_url target="_blank" rel="noopener noreferrer">
+                        {move || i18n.text(p.cta_label)}
+                    </a>
+                    <Show when=move || !p.demo_url.is_empty() fallback=|| ()>
+                        <a class=BTN_GHOST href=p.demo_url target="_blank" rel="noopener noreferrer">
+                            {move || i18n.text(p.demo_label)}
+                        </a>
+                    </Show>
+                </div>
+            </div>
+        </section>
+    }
+}
+
+#[component]
+fn CaseStudyBody(cs: CaseStudy) -> impl IntoView {
+    view! {
+        <div class="space-y-10">
+            <CaseSection title=|t| t.case_study_problem>
+                {cs.problem.iter().map(|para| view! { <p>{*para}</p> }).collect_view()}
+            </CaseSection>
+
+            <Show when=move || !cs.media.is_empty() fallback=|| ()>
+                <div class="grid gap-6 sm:grid-cols-2">
+                    {cs
+                        .media
+                        .iter()
+                        .map(|m| {
+                            view! {
+                                <figure class="
+                                relative overflow-hidden rounded-2xl
+                                border border-white/10
+                                bg-black/40
+                                shadow-[inset_0_1px_0_rgba(255,255,255,0.08)]
+                                ">
+                                    {if m.is_video() {
+                                        view! {
+                                            <AutoVideo
+                                                media=*m
+                                                class="w-full h-auto object-contain rounded-2xl"
+                                            />
+                                        }
+                                            .into_any()
+                                    } else {
+                                        view! {
+                                            <img
+                                                class="w-full h-auto object-contain rounded-2xl"
+                                                src=m.image().map(media_href)
+                                                alt=m.label
+                                                loading="lazy"
+                                            />
+                                        }
+                                            .into_any()
+                                    }}
+                                    <figcaption class="px-4 py-2 text-xs font-mono text-white/45">
+                                        {m.label}
+                                    </figcaption>
+                                </figure>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+            </Show>
+
+            <CaseSection title=|t| t.case_study_architecture>
+                {cs.architecture.iter().map(|para| view! { <p>{*para}</p> }).collect_view()}
+            </CaseSection>
+
+            <Show when=move || !cs.challenges.is_empty() fallback=|| ()>
+                <CaseSection title=|t| t.case_study_challenges>
+                    <ul class="list-disc pl-5 space-y-2">
+                        {cs.challenges.iter().map(|c| view! { <li>{*c}</li> }).collect_view()}
+                    </ul>
+                </CaseSection>
+            </Show>
+
+            <Show when=move || !cs.results.is_empty() fallback=|| ()>
+                <CaseSection title=|t| t.case_study_results>
+                    <ul class="list-disc pl-5 space-y-2">
+                        {cs.results.iter().map(|r| view! { <li>{*r}</li> }).collect_view()}
+                    </ul>
+                </CaseSection>
+            </Show>
+
+            <Show when=move || !cs.links.is_empty() fallback=|| ()>
+                <CaseSection title=|t| t.case_study_links>
+                    <ul class="space-y-1 font-mono text-sm">
+                        {cs
+                            .links
+                            .iter()
+                            .map(|l| {
+                                view! {
+                                    <li>
+                                        <a
+                                            href=l.url
+                                            target="_blank"
+                                            rel="noopener noreferrer"
+                                            class="text-white/70 hover:text-white transition"
+                                        >
+                                            {format!("{} ↗", l.label)}
+                                        </a>
+                                    </li>
+                                }
+                            })
+                            .collect_view()}
+                    </ul>
+                </CaseSection>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn CaseSection(title: fn(&Strings) -> &'static str, children: Children) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class=format!("{GLASS_CARD} p-6 sm:p-8")>
+            <div class="relative z-10 space-y-4">
+                <h2 class="text-xl font-semibold text-white/90">{move || title(i18n.t())}</h2>
+                <div class="space-y-3 text-white/65 leading-relaxed">{children()}</div>
+            </div>
+        </div>
+    }
+}
@@ -1,44 +1,41 @@
-use leptos::html;
 use leptos::prelude::*;
-use wasm_bindgen::JsCast;
-
-use crate::utils::projects_data::{PROJECTS, Project};
-
-fn svg_data_url(svg: &str) -> String {
-    let mut out = String::with_capacity(svg.len() + 32);
-    out.push_str("data:image/svg+xml;utf8,");
-    for c in svg.chars() {
-        match c {
-            '#' => out.push_str("%23"),
-            '&' => out.push_str("%26"),
-            '<' => out.push_str("%3C"),
-            '>' => out.push_str("%3E"),
-            '"' => out.push_str("%22"),
-            '\'' => out.push_str("%27"),
-            ' ' => out.push_str("%20"),
-            '\n' | '\r' | '\t' => {}
-            _ => out.push(c),
-        }
-    }
-    out
-}
 
-fn is_video(src: &str) -> bool {
-    src.ends_with(".webm") || src.ends_with(".mp4")
-}
+use crate::components::AutoVideo;
+use crate::utils::glass::{CHIP, CHIP_ACTIVE};
+use crate::utils::i18n::{fill, use_i18n};
+use crate::utils::media::{media_href, svg_data_url};
+use crate::utils::project_filter::{self, ProjectFilter, SortMode, toggle};
+use crate::utils::projects_data::{PROJECTS, Project, RepoStats};
+use crate::utils::scroll;
+use crate::utils::site_data::SITE;
 
 #[component]
 pub fn Projects() -> impl IntoView {
+    let i18n = use_i18n();
+    let filter = expect_context::<RwSignal<ProjectFilter>>();
+
+    // keep ?lang=..&tag=..&status=..&sort=.. in sync so filtered views can be
+    // shared; not on the first run, before `Sections` has read them
+    Effect::new(move |prev: Option<()>| {
+        filter.track();
+        if prev.is_some() {
+            filter.with_untracked(ProjectFilter::write_location);
+        }
+    });
+
+    let visible = Memo::new(move |_| filter.with(|f| f.apply(PROJECTS, i18n.locale())));
+    let no_matches = move || visible.with(|v| v.iter().all(|p| p.pinned));
+
     view! {
         <section id="projects" class="  px-4 sm:px-6 lg:px-8 pt-16 sm:pt-20">
             <div class="mx-auto max-w-6xl">
                 <div class="mb-10 flex items-end justify-between gap-6">
                     <div class="space-y-2">
                         <h2 class="text-3xl sm:text-4xl font-semibold tracking-tight text-white/90">
-                            "Projects"
+                            {move || i18n.t().projects_title}
                         </h2>
                         <p class="text-white/60 max-w-prose leading-relaxed">
-                            "Selected projects exploring systems, tooling, and performance-driven engineering."
+                            {move || i18n.t().projects_subtitle}
                         </p>
                     </div>
                     <div class="hidden sm:block text-xs font-mono text-white/40">
@@ -46,19 +43,29 @@ pub fn Projects() -> impl IntoView {
                     </div>
                 </div>
 
+                <ProjectFilterBar filter=filter />
+
+                <Show when=no_matches fallback=|| ()>
+                    <p class="mb-6 text-sm text-white/50 font-mono">
+                        {move || i18n.t().projects_no_matches}
+                    </p>
+                </Show>
+
                 <div class="space-y-6">
                     <For
                         // IMPORTANT: iterate Projects by value (Copy) so key gets &Project (NOT &&Project)
-                        each=move || PROJECTS.iter().copied()
+                        each=move || visible.get()
                         key=|p: &Project| p.id
                         children=move |p: Project| {
-                            let has_media = !p.media_src.is_empty();
+                            let has_media = p.media.is_video() || p.media.image().is_some();
                             let has_lang = !p.language_label.is_empty();
                             let has_demo = !p.demo_url.is_empty();
                             let has_icon = p.language_svg.is_some();
 
                             view! {
-                                <article class="
+                                <article
+                                    id=format!("project-{}", p.id)
+                                    class="
                                 relative overflow-hidden rounded-3xl
                                 border border-white/15
                                 bg-gradient-to-br from-white/14 via-white/8 to-white/4
@@ -80,7 +87,7 @@ pub fn Projects() -> impl IntoView {
                                                     fallback=move || {
                                                         view! {
                                                             <div class="h-full w-full flex items-center justify-center px-6 text-sm text-white/40">
-                                                                <span class="font-mono">{p.media_label}</span>
+                                                                <span class="font-mono">{p.media.label}</span>
                                                             </div>
                                                         }
                                                     }
@@ -92,20 +99,20 @@ pub fn Projects() -> impl IntoView {
                                                     shadow-[inset_0_1px_0_rgba(255,255,255,0.08)]
                                                     ">
                                                         <Show
-                                                            when=move || is_video(p.media_src)
+                                                            when=move || p.media.is_video()
                                                             fallback=move || {
                                                                 view! {
                                                                     <img
                                                                         class="w-full h-auto object-contain rounded-2xl"
-                                                                        src=p.media_src
-                                                                        alt=p.name
+                                                                        src=p.media.image().map(media_href)
+                                                                        alt=move || i18n.text(p.name)
                                                                         loading="lazy"
                                                                     />
                                                                 }
                                                             }
                                                         >
                                                             <AutoVideo
-                                                                src=p.media_src
+                                                                media=p.media
                                                                 class="w-full h-auto object-contain rounded-2xl"
                                                             />
 
@@ -120,7 +127,7 @@ pub fn Projects() -> impl IntoView {
                                             <div class="flex items-start justify-between gap-4">
                                                 <div class="space-y-1">
                                                     <h3 class="text-xl sm:text-2xl font-semibold text-white/90 tracking-tight">
-                                                        {p.name}
+                                                        {move || i18n.text(p.name)}
                                                     </h3>
 
                                                     <Show when=move || has_lang fallback=|| ()>
@@ -143,6 +150,22 @@ pub fn Projects() -> impl IntoView {
                                                             <span>{p.language_label}</span>
                                                         </span>
                                                     </Show>
+
+                                                    <div class="flex flex-wrap gap-1.5 pt-1">
+                                                        {p
+                                                            .tags
+                                                            .iter()
+                                                            .map(|t| {
+                                                                view! {
+                                                                    <span class="font-mono text-[11px] text-white/45">
+                                                                        {format!("#{}", t.to_lowercase())}
+                                                                    </span>
+                                                                }
+                                                            })
+                                                            .collect_view()}
+                                                    </div>
+
+                                                    {p.stats.map(|stats| view! { <RepoStatsLine stats=stats /> })}
                                                 </div>
 
                                                 <span class="text-xs font-mono text-white/35">
@@ -151,7 +174,7 @@ pub fn Projects() -> impl IntoView {
                                             </div>
 
                                             <p class="mt-4 text-white/65 leading-relaxed">
-                                                {p.description}
+                                                {move || i18n.text(p.description)}
                                             </p>
 
                                             <div class="mt-5 flex flex-wrap items-center gap-3">
@@ -169,7 +192,7 @@ pub fn Projects() -> impl IntoView {
                                                     hover:bg-white/15 hover:border-white/25 hover:text-white
                                                     "
                                                 >
-                                                    {p.cta_label}
+                                                    {move || i18n.text(p.cta_label)}
                                                 </a>
 
                                                 <Show when=move || has_demo fallback=|| ()>
@@ -188,7 +211,18 @@ pub fn Projects() -> impl IntoView {
                                                         hover:bg-white/10 hover:border-white/20 hover:text-white
                                                         "
                                                     >
-                                                        {p.demo_label}
+                                                        {move || i18n.text(p.demo_label)}
+                                                    </a>
+                                                </Show>
+
+                                                <Show when=move || !p.pinned fallback=|| ()>
+                                                    // the router intercepts this, no full reload
+                                                    <a
+                                                        href=SITE.href(&format!("/projects/{}", p.id))
+                                                        on:click=|_| scroll::remember()
+                                                        class="text-sm font-mono text-white/55 hover:text-white/85 transition"
+                                                    >
+                                                        {move || i18n.t().projects_case_study}
                                                     </a>
                                                 </Show>
                                             </div>
@@ -204,48 +238,141 @@ pub fn Projects() -> impl IntoView {
     }
 }
 
+/// "★ 12 · updated Nov 2025 · Rust, Shell · archived"
 #[component]
-fn AutoVideo(src: &'static str, class: &'static str) -> impl IntoView {
-    let vref = NodeRef::<html::Video>::new();
-
-    let try_play = move || {
-        if let Some(v) = vref.get() {
-            let v: web_sys::HtmlVideoElement = v.into();
-            v.set_muted(true); // IMPORTANT: property, not just attribute
-            let _ = v.play(); // Chromium may block; ignore error
+fn RepoStatsLine(stats: RepoStats) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let line = move || {
+        let t = i18n.t();
+        let mut parts = vec![format!("★ {}", stats.stars)];
+        let month = stats.last_commit_month(t.date_months);
+        if !month.is_empty() {
+            parts.push(fill(t.projects_stats_updated, &[("date", &month)]));
+        }
+        if !stats.languages.is_empty() {
+            parts.push(stats.languages.join(", "));
         }
+        if stats.archived {
+            parts.push(t.projects_stats_archived.into());
+        }
+        parts.join(" · ")
     };
 
-    Effect::new({
-        let try_play = try_play.clone();
-        move || try_play()
-    });
+    view! {
+        <p
+            class="pt-1 font-mono text-[11px] text-white/40"
+            title=move || i18n.t().projects_stats_title
+        >
+            {line}
+        </p>
+    }
+}
+
+#[component]
+fn ProjectFilterBar(filter: RwSignal<ProjectFilter>) -> impl IntoView {
+    let i18n = use_i18n();
+    let languages = project_filter::languages(PROJECTS);
+    let tags = project_filter::tags(PROJECTS);
+    let statuses = project_filter::statuses(PROJECTS);
 
-    Effect::new({
-        let try_play = try_play.clone();
-        move || {
-            let doc = web_sys::window().unwrap().document().unwrap();
-            let cb = wasm_bindgen::closure::Closure::<dyn FnMut(web_sys::Event)>::wrap(Box::new(
-                move |_| try_play(),
-            ));
-            let _ =
-                doc.add_event_listener_with_callback("pointerdown", cb.as_ref().unchecked_ref());
-            cb.forget();
+    let chip = move |label: Signal<&'static str>, active: Signal<bool>, on_toggle: Callback<()>| {
+        view! {
+            <button
+                type="button"
+                class=move || if active.get() { CHIP_ACTIVE } else { CHIP }
+                aria-pressed=move || active.get().to_string()
+                on:click=move |_| on_toggle.run(())
+            >
+                {label}
+            </button>
         }
-    });
+    };
 
     view! {
-        <video
-            node_ref=vref
-            class=class
-            prop:muted=true
-            prop:autoplay=true
-            prop:loop=true
-            playsinline
-            preload="metadata"
-            on:loadedmetadata=move |_| try_play()
-        >
-            <source src=src type="video/webm" />
-        </video>
+        <div class="mb-8 space-y-3">
+            {move || {
+                filter
+                    .with(|f| f.skill)
+                    .map(|skill| {
+                        view! {
+                            <FilterRow label="skill">
+                                {chip(
+                                    Signal::stored(skill),
+                                    Signal::stored(true),
+                                    Callback::new(move |_| filter.update(|f| f.skill = None)),
+                                )}
+                            </FilterRow>
+                        }
+                    })
+            }}
+            <FilterRow label="lang">
+                {languages
+                    .into_iter()
+                    .map(|l| {
+                        chip(
+                            Signal::stored(l),
+                            Signal::derive(move || filter.with(|f| f.languages.contains(&l))),
+                            Callback::new(move |_| filter.update(|f| toggle(&mut f.languages, l))),
+                        )
+                    })
+                    .collect_view()}
+            </FilterRow>
+            <FilterRow label="tag">
+                {tags
+                    .into_iter()
+                    .map(|t| {
+                        chip(
+                            Signal::stored(t),
+                            Signal::derive(move || filter.with(|f| f.tags.contains(&t))),
+                            Callback::new(move |_| filter.update(|f| toggle(&mut f.tags, t))),
+                        )
+                    })
+                    .collect_view()}
+            </FilterRow>
+            <FilterRow label="status">
+                {statuses
+                    .into_iter()
+                    .map(|s| {
+                        chip(
+                            Signal::derive(move || s.label(i18n.t())),
+                            Signal::derive(move || filter.with(|f| f.statuses.contains(&s))),
+                            Callback::new(move |_| filter.update(|f| toggle(&mut f.statuses, s))),
+                        )
+                    })
+                    .collect_view()}
+            </FilterRow>
+            <FilterRow label="sort">
+                {SortMode::ALL
+                    .into_iter()
+                    .map(|m| {
+                        chip(
+                            Signal::derive(move || m.label(i18n.t())),
+                            Signal::derive(move || filter.with(|f| f.sort == m)),
+                            Callback::new(move |_| filter.update(|f| f.sort = m)),
+                        )
+                    })
+                    .collect_view()}
+                <Show when=move || !filter.with(ProjectFilter::is_empty) fallback=|| ()>
+                    <button
+                        type="button"
+                        class="ml-auto text-xs font-mono text-white/45 hover:text-white/80 transition"
+                        on:click=move |_| filter.update(ProjectFilter::clear)
+                    >
+                        {move || i18n.t().projects_clear_filters}
+                    </button>
+                </Show>
+            </FilterRow>
+        </div>
+    }
+}
+
+#[component]
+fn FilterRow(label: &'static str, children: Children) -> impl IntoView {
+    view! {
+        <div class="flex flex-wrap items-center gap-2">
+            <span class="w-14 shrink-0 text-xs font-mono text-white/40">{format!("--{label}")}</span>
+            {children()}
+        </div>
     }
 }
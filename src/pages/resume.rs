@@ -0,0 +1,229 @@
+use leptos::prelude::*;
+
+use crate::utils::glass::{BTN_GHOST, BTN_PRIMARY};
+use crate::utils::i18n::{I18n, use_i18n};
+use crate::utils::meta::{PageMeta, use_page_meta};
+use crate::utils::profile_data::PROFILE;
+use crate::utils::projects_data::find_project;
+use crate::utils::resume_data::{RESUME, ResumeSection};
+use crate::utils::site_data::SITE;
+use crate::utils::skills_data::GROUPS;
+
+/// The résumé as a page of paper, which is also how it prints: everything
+/// else on the site is `print:hidden`. cv.pdf has the same content, laid out
+/// by build/resume.rs.
+#[component]
+pub fn Resume() -> impl IntoView {
+    let i18n = use_i18n();
+    use_page_meta(move || PageMeta {
+        title: Some(i18n.t().resume_title.to_string()),
+        ..PageMeta::site("/resume")
+    });
+
+    let print = move |_| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.print();
+        }
+    };
+
+    view! {
+        <section class="px-4 sm:px-6 lg:px-8 pt-28 sm:pt-32 print:p-0">
+            <div class="mx-auto max-w-3xl">
+                <div class="mb-6 flex flex-wrap items-center justify-between gap-4 print:hidden">
+                    <a href=SITE.href("/") class="text-sm font-mono text-white/50 hover:text-white/85 transition">
+                        "← cd ~"
+                    </a>
+                    <div class="flex flex-wrap gap-3">
+                        <button type="button" class=BTN_GHOST on:click=print>
+                            {move || i18n.t().resume_print}
+                        </button>
+                        // rel=external: a file next to index.html, not a route
+                        <a class=BTN_PRIMARY href=SITE.href(RESUME.pdf) rel="external" download>
+                            {move || i18n.t().resume_download}
+                        </a>
+                    </div>
+                </div>
+
+                <article class="rounded-2xl bg-white text-slate-700 shadow-2xl p-8 sm:p-12 print:rounded-none print:shadow-none print:p-0">
+                    <ResumeHeader />
+                    {RESUME
+                        .sections
+                        .iter()
+                        .map(|section| view! { <ResumeSectionView section=*section /> })
+                        .collect_view()}
+                </article>
+            </div>
+        </section>
+    }
+}
+
+#[component]
+fn ResumeHeader() -> impl IntoView {
+    let i18n = use_i18n();
+    let home = SITE.url("/");
+
+    view! {
+        <header class="space-y-2">
+            <h1 class="text-3xl font-bold tracking-tight text-slate-900">{PROFILE.name}</h1>
+            <p class="text-lg text-slate-500">{move || i18n.text(PROFILE.role)}</p>
+            <p class="text-sm leading-relaxed">{move || i18n.text(PROFILE.headline)}</p>
+            <p class="flex flex-wrap gap-x-2 text-xs text-slate-500">
+                <a class=RESUME_LINK href=PROFILE.mailto()>
+                    {PROFILE.email}
+                </a>
+                {PROFILE
+                    .socials
+                    .iter()
+                    .map(|s| {
+                        view! {
+                            <span aria-hidden="true">"·"</span>
+                            <a class=RESUME_LINK href=s.url>
+                                {display_url(s.url)}
+                            </a>
+                        }
+                    })
+                    .collect_view()}
+                <span aria-hidden="true">"·"</span>
+                <a class=RESUME_LINK href=home.clone() rel="external">
+                    {display_url(&home).to_string()}
+                </a>
+                {PROFILE
+                    .location
+                    .map(|location| {
+                        view! {
+                            <span aria-hidden="true">"·"</span>
+                            <span>{move || i18n.text(location)}</span>
+                        }
+                    })}
+            </p>
+        </header>
+    }
+}
+
+const RESUME_LINK: &str = "hover:text-slate-900 underline-offset-2 hover:underline";
+
+#[component]
+fn ResumeSectionView(section: ResumeSection) -> impl IntoView {
+    let i18n = use_i18n();
+    let title = move || {
+        let t = i18n.t();
+        match section {
+            ResumeSection::Summary => t.resume_summary,
+            ResumeSection::Skills => t.resume_skills,
+            ResumeSection::Projects => t.resume_projects,
+            ResumeSection::Languages => t.resume_languages,
+        }
+    };
+
+    view! {
+        <section class="mt-8 break-inside-avoid-page">
+            <h2 class="mb-3 border-b border-slate-300 pb-1 text-sm font-bold uppercase tracking-wide text-slate-900">
+                {title}
+            </h2>
+            {match section {
+                ResumeSection::Summary => summary(i18n).into_any(),
+                ResumeSection::Skills => skills(i18n).into_any(),
+                ResumeSection::Projects => projects(i18n).into_any(),
+                ResumeSection::Languages => languages(i18n).into_any(),
+            }}
+        </section>
+    }
+}
+
+fn summary(i18n: I18n) -> impl IntoView {
+    view! {
+        <div class="space-y-2 text-sm leading-relaxed">
+            <p>{move || i18n.t().about_p1}</p>
+            <p>{move || i18n.t().about_p2}</p>
+        </div>
+    }
+}
+
+fn skills(i18n: I18n) -> impl IntoView {
+    let groups = GROUPS.iter().filter_map(|g| {
+        let items: Vec<&str> = g
+            .items
+            .iter()
+            .filter(|i| RESUME.shows_skill(i.proficiency))
+            .map(|i| i.name)
+            .collect();
+        (!items.is_empty()).then_some((g.title, items.join(", ")))
+    });
+
+    view! {
+        <ul class="space-y-1.5 text-sm leading-relaxed">
+            {groups
+                .map(|(title, items)| {
+                    view! {
+                        <li>
+                            <span class="font-semibold text-slate-900">
+                                {move || format!("{}: ", i18n.text(title))}
+                            </span>
+                            {items}
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+}
+
+fn projects(i18n: I18n) -> impl IntoView {
+    view! {
+        <div class="space-y-4">
+            {RESUME
+                .projects
+                .iter()
+                .filter_map(|id| find_project(id))
+                .map(|p| {
+                    let details: Vec<&str> = [Some(p.language_label), p.date]
+                        .into_iter()
+                        .flatten()
+                        .filter(|s| !s.is_empty())
+                        .collect();
+                    view! {
+                        <div class="break-inside-avoid text-sm leading-relaxed">
+                            <div class="flex flex-wrap items-baseline gap-x-2">
+                                <a
+                                    href=SITE.href(&format!("/projects/{}", p.id))
+                                    class="font-semibold text-slate-900 hover:underline underline-offset-2"
+                                >
+                                    {move || i18n.text(p.name)}
+                                </a>
+                                <span class="text-xs text-slate-500">{details.join(" · ")}</span>
+                            </div>
+                            <p>{move || i18n.text(p.description)}</p>
+                            <a class=format!("text-xs text-slate-500 {RESUME_LINK}") href=p.repo_url>
+                                {display_url(p.repo_url)}
+                            </a>
+                        </div>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+fn languages(i18n: I18n) -> impl IntoView {
+    view! {
+        <p class="text-sm">
+            {move || {
+                PROFILE
+                    .languages
+                    .iter()
+                    .map(|l| format!("{} ({})", i18n.text(l.name), i18n.text(l.level)))
+                    .collect::<Vec<_>>()
+                    .join(" · ")
+            }}
+        </p>
+    }
+}
+
+/// "github.com/araujoviana" for "https://github.com/araujoviana/", as the PDF
+/// shows links too.
+fn display_url(url: &str) -> &str {
+    url.trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("www.")
+        .trim_end_matches('/')
+}
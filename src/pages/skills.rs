@@ -1,19 +1,24 @@
 use crate::utils::glass::{GLASS_SKILL_ENTRY, GLASS_SKILLS};
-use crate::utils::skills_data::{GROUPS, SkillItem};
+use crate::utils::i18n::{I18n, Text, fill, use_i18n};
+use crate::utils::project_filter::ProjectFilter;
+use crate::utils::projects_data::find_project;
+use crate::utils::skills_data::{GROUPS, Proficiency, SkillItem};
 use leptos::prelude::*;
 
 #[component]
 pub fn Skills() -> impl IntoView {
+    let i18n = use_i18n();
+
     view! {
         <section id="skills" class="px-4 sm:px-6 lg:px-8 pt-16 sm:pt-20">
             <div class="mx-auto max-w-6xl">
                 <div class="mb-10 flex items-end justify-between gap-6">
                     <div class="space-y-2">
                         <h2 class="text-3xl sm:text-4xl font-semibold tracking-tight text-white/90">
-                            "Skills"
+                            {move || i18n.t().skills_title}
                         </h2>
                         <p class="text-white/60 max-w-prose leading-relaxed">
-                            "Tools, frameworks, and systems knowledge I’m comfortable owning end-to-end."
+                            {move || i18n.t().skills_subtitle}
                         </p>
                     </div>
                     <div class="hidden sm:block text-xs font-mono text-white/40">
@@ -21,11 +26,13 @@ pub fn Skills() -> impl IntoView {
                     </div>
                 </div>
 
+                <ProficiencyLegend />
+
                 <div class="grid gap-6 lg:grid-cols-3">
                     <For
                         // Return the slice directly => items are &SkillGroup
                         each=move || GROUPS
-                        key=|g| g.title
+                        key=|g| g.title.en
                         children=move |g| {
                             view! { <SkillCard title=g.title subtitle=g.subtitle items=g.items /> }
                         }
@@ -37,29 +44,35 @@ pub fn Skills() -> impl IntoView {
 }
 
 #[component]
-fn SkillCard(
-    title: &'static str,
-    subtitle: &'static str,
-    items: &'static [SkillItem],
-) -> impl IntoView {
+fn SkillCard(title: Text, subtitle: Text, items: &'static [SkillItem]) -> impl IntoView {
+    let i18n = use_i18n();
+
     view! {
         <div class=GLASS_SKILLS>
             <div class="relative z-10">
                 <div class="mb-5">
-                    <h3 class="text-lg font-semibold text-white/90">{title}</h3>
-                    <p class="mt-1 text-sm text-white/55">{subtitle}</p>
+                    <h3 class="text-lg font-semibold text-white/90">
+                        {move || i18n.text(title)}
+                    </h3>
+                    <p class="mt-1 text-sm text-white/55">{move || i18n.text(subtitle)}</p>
                 </div>
 
                 <div class="flex flex-wrap gap-2">
                     <For
                         each=move || items
-                        key=|&(name, _)| name
-                        children=move |&(name, level)| {
+                        key=|item| item.id
+                        children=move |item| {
                             view! {
-                                <span class=GLASS_SKILL_ENTRY>
-                                    <span class="font-medium">{name}</span>
-                                    <span class="text-white/40">"•"</span>
-                                    <span class="font-mono text-[11px] text-white/50">{level}</span>
+                                <span
+                                    id=format!("skill-{}", item.id)
+                                    class=GLASS_SKILL_ENTRY
+                                    title=move || details(item, i18n)
+                                >
+                                    <span class="font-medium">{item.name}</span>
+                                    <ProficiencyScale level=item.proficiency />
+                                    <Show when=move || !item.projects.is_empty() fallback=|| ()>
+                                        <BackingProjects item=*item />
+                                    </Show>
                                 </span>
                             }
                         }
@@ -69,3 +82,104 @@ fn SkillCard(
         </div>
     }
 }
+
+/// Count of projects using a skill; clicking shows them in the Projects section.
+#[component]
+fn BackingProjects(item: SkillItem) -> impl IntoView {
+    let i18n = use_i18n();
+    let filter = use_context::<RwSignal<ProjectFilter>>();
+
+    let names = move || {
+        item.projects
+            .iter()
+            .filter_map(|id| find_project(id))
+            .map(|p| i18n.text(p.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let on_click = move |_| {
+        let Some(filter) = filter else { return };
+        filter.update(|f| f.only_skill(item.name));
+
+        if let Some(el) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.get_element_by_id("projects"))
+        {
+            el.scroll_into_view();
+        }
+    };
+
+    view! {
+        <button
+            type="button"
+            class="
+            rounded-full px-1.5
+            font-mono text-[10px]
+            bg-white/10 text-white/70
+            hover:bg-white/20 hover:text-white
+            transition
+            "
+            title=move || fill(i18n.t().skills_used_in, &[("names", &names())])
+            aria-label=move || fill(i18n.t().skills_show_projects, &[("skill", item.name)])
+            on:click=on_click
+        >
+            {item.projects.len()}
+        </button>
+    }
+}
+
+#[component]
+fn ProficiencyScale(level: Proficiency) -> impl IntoView {
+    let i18n = use_i18n();
+    let steps = Proficiency::ALL.len();
+
+    view! {
+        <span class="inline-flex items-center gap-0.5" role="img" aria-label=move || level.label(i18n.t())>
+            {(1..=steps)
+                .map(|i| {
+                    let fill = if i <= level.rank() { "bg-white/75" } else { "bg-white/15" };
+                    view! { <span class=format!("h-1.5 w-1.5 rounded-full {fill}")></span> }
+                })
+                .collect_view()}
+        </span>
+    }
+}
+
+#[component]
+fn ProficiencyLegend() -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class="mb-6 flex flex-wrap items-center gap-x-5 gap-y-2 text-[11px] font-mono text-white/50">
+            {Proficiency::ALL
+                .into_iter()
+                .map(|level| {
+                    view! {
+                        <span class="inline-flex items-center gap-2">
+                            <ProficiencyScale level=level />
+                            {move || level.label(i18n.t()).to_lowercase()}
+                        </span>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+/// Hover text: "Solid · 3 yrs · last used 2025-01 · design"
+fn details(item: &SkillItem, i18n: I18n) -> String {
+    let t = i18n.t();
+    let mut parts = vec![item.proficiency.label(t).to_string()];
+    if let Some(y) = item.years {
+        let template = if y == 1 { t.skills_year } else { t.skills_years };
+        parts.push(fill(template, &[("n", &y.to_string())]));
+    }
+    if let Some(d) = item.last_used {
+        parts.push(fill(t.skills_last_used, &[("date", d)]));
+    }
+    if let Some(n) = item.note {
+        parts.push(i18n.text(n).to_string());
+    }
+    parts.join(" · ")
+}
@@ -0,0 +1,185 @@
+// Static pages for every route of `App`, so the site has content before the
+// WASM loads. The native `ssr` build renders them into the dist/ Trunk made
+// (see scripts/prerender.sh); the browser build then hydrates a page when it
+// is the one for the current URL, and renders from scratch otherwise.
+
+use crate::utils::site_data::SITE;
+
+/// Set on <body> to the route a prerendered page was rendered for.
+const ROUTE_ATTR: &str = "data-prerendered";
+
+/// "/skills/" and "/skills" are the same page.
+#[cfg(not(feature = "ssr"))]
+fn normalize(path: &str) -> &str {
+    match path.trim_end_matches('/') {
+        "" => "/",
+        p => p,
+    }
+}
+
+/// Whether <body> holds the prerendered page for the current URL. Hosts may
+/// serve one route's file for another (a 404 falling back to index.html),
+/// which must not be hydrated.
+#[cfg(not(feature = "ssr"))]
+pub fn is_current_page() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    let Some(route) = window
+        .document()
+        .and_then(|d| d.body())
+        .and_then(|b| b.get_attribute(ROUTE_ATTR))
+    else {
+        return false;
+    };
+    let path = window.location().pathname().unwrap_or_default();
+    // the attribute has the route, the URL has it under the base path
+    normalize(SITE.route(&path)) == normalize(&route)
+}
+
+/// Drop another route's prerendered markup before rendering from scratch.
+#[cfg(not(feature = "ssr"))]
+pub fn clear_body() {
+    let Some(body) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.body())
+    else {
+        return;
+    };
+    let _ = body.remove_attribute(ROUTE_ATTR);
+    let children = body.children();
+    for i in (0..children.length()).rev() {
+        if let Some(el) = children.item(i)
+            && el.tag_name() != "SCRIPT"
+        {
+            el.remove();
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use render::run;
+
+#[cfg(feature = "ssr")]
+mod render {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process;
+    use std::sync::Arc;
+
+    use hydration_context::SsrSharedContext;
+    use leptos::prelude::*;
+    use leptos_router::location::RequestUrl;
+
+    use super::{ROUTE_ATTR, SITE};
+    use crate::app::App;
+    use crate::routes;
+    use crate::utils::meta::Head;
+
+    /// Rendered for any unknown path; hosts serve it as their 404 page.
+    const NOT_FOUND: &str = "/404";
+
+    // Nothing in the app loads data asynchronously, so what the hydration
+    // context would serialize for the client is always empty.
+    const HYDRATION_DATA: &str =
+        "<script>__RESOLVED_RESOURCES=[];__SERIALIZED_ERRORS=[];__INCOMPLETE_CHUNKS=[];</script>";
+
+    /// `prerender [dist]`: turn the index.html Trunk built into one page per
+    /// route, next to it, and list them in sitemap.xml.
+    pub fn run() {
+        let dist = env::args()
+            .nth(1)
+            .map_or_else(|| PathBuf::from("dist"), PathBuf::from);
+        let shell = match fs::read_to_string(dist.join("index.html")) {
+            Ok(s) => s,
+            Err(e) => fail(&format!("{}: {e}", dist.join("index.html").display())),
+        };
+        if shell.contains(ROUTE_ATTR) {
+            fail("dist/ is already prerendered, run `trunk build` first");
+        }
+
+        for route in routes() {
+            let (app, head) = render(&route);
+            let page = fill_shell(&shell, &route, &app, &head);
+            let file = dist.join(file_for(&route));
+            if let Err(e) = write(&file, &page) {
+                fail(&format!("{}: {e}", file.display()));
+            }
+            println!("{route} -> {}", file.display());
+        }
+
+        for (name, text) in [
+            ("sitemap.xml", routes::sitemap()),
+            ("robots.txt", routes::robots()),
+        ] {
+            let file = dist.join(name);
+            if let Err(e) = write(&file, &text) {
+                fail(&format!("{}: {e}", file.display()));
+            }
+            println!("{}", file.display());
+        }
+    }
+
+    /// Every page, plus the 404 page (which isn't in the sitemap).
+    fn routes() -> Vec<String> {
+        let mut routes: Vec<String> = routes::all().into_iter().map(|r| r.path).collect();
+        routes.push(NOT_FOUND.into());
+        routes
+    }
+
+    /// "/" -> index.html, "/skills" -> skills/index.html, "/404" -> 404.html
+    fn file_for(route: &str) -> PathBuf {
+        match route {
+            "/" => "index.html".into(),
+            NOT_FOUND => "404.html".into(),
+            _ => Path::new(route.trim_start_matches('/')).join("index.html"),
+        }
+    }
+
+    /// The app's HTML, and the <head> tags for the page it rendered.
+    fn render(route: &str) -> (String, String) {
+        let owner = Owner::new_root(Some(Arc::new(SsrSharedContext::new())));
+        owner.with(|| {
+            // as the browser would ask for it, under the router's base
+            provide_context(RequestUrl::new(&SITE.href(route)));
+            let head = Head::new();
+            provide_context(head);
+            let app = view! { <App /> }.to_html();
+            (app, head.get().to_html())
+        })
+    }
+
+    /// The app goes first in <body>, where hydration expects it.
+    fn fill_shell(shell: &str, route: &str, app: &str, head: &str) -> String {
+        let Some(body) = shell.find("<body") else {
+            fail("index.html has no <body>");
+        };
+        let Some(body_end) = shell[body..].find('>').map(|i| body + i) else {
+            fail("index.html has an unterminated <body> tag");
+        };
+        let Some(head_end) = shell.find("</head>") else {
+            fail("index.html has no </head>");
+        };
+
+        format!(
+            "{}{head}    {HYDRATION_DATA}\n  {} {ROUTE_ATTR}=\"{route}\">{app}{}",
+            // the tags are indented like the shell's own
+            shell[..head_end].trim_end_matches(' '),
+            &shell[head_end..body_end],
+            &shell[body_end + 1..],
+        )
+    }
+
+    fn write(file: &Path, page: &str) -> std::io::Result<()> {
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(file, page)
+    }
+
+    fn fail(msg: &str) -> ! {
+        eprintln!("prerender: {msg}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,211 @@
+// Every page the site has: the patterns src/app.rs gives its <Route>s, and
+// the paths each one has a page at. The prerenderer writes one file per
+// path, and sitemap.xml lists them all.
+
+use leptos_router::{OptionalParamSegment, ParamSegment, StaticSegment};
+
+#[cfg(feature = "ssr")]
+pub use listing::{all, robots, sitemap};
+
+pub const RESUME: (StaticSegment<&str>,) = (StaticSegment("resume"),);
+/// "/", "/skills", "/projects", ...; unknown sections are a 404.
+pub const MAIN_PAGE: (OptionalParamSegment,) = (OptionalParamSegment("section"),);
+pub const PROJECT: (StaticSegment<&str>, ParamSegment) =
+    (StaticSegment("projects"), ParamSegment("id"));
+pub const POST: (StaticSegment<&str>, ParamSegment) =
+    (StaticSegment("posts"), ParamSegment("slug"));
+
+#[cfg(any(feature = "ssr", test))]
+mod listing {
+    use std::fmt::Write as _;
+
+    use leptos_router::PossibleRouteMatch;
+
+    use super::{MAIN_PAGE, POST, PROJECT, RESUME};
+    use crate::utils::meta::escape;
+    use crate::utils::posts_data::{POSTS, find_post};
+    use crate::utils::projects_data::{PROJECTS, find_project};
+    use crate::utils::sections::{self, SECTIONS};
+    use crate::utils::site_data::SITE;
+
+    pub struct Route {
+        pub path: String,
+        /// W3C date of the newest content on the page ("2026-10-18", "2025-11").
+        pub lastmod: Option<String>,
+    }
+
+    /// A <Route> of the app. The tests check `routes` against `pattern` and
+    /// `found`, which nothing else reads.
+    #[cfg_attr(not(test), allow(dead_code))]
+    struct Page {
+        pattern: &'static (dyn PossibleRouteMatch + Sync),
+        /// Whether the page has something for its param, by the same lookup
+        /// its view does; `None` when the pattern has no param, or the
+        /// optional one is left out.
+        found: fn(Option<&str>) -> bool,
+        /// Every path it has a page at, given the newest content overall.
+        routes: fn(&Option<String>) -> Vec<Route>,
+    }
+
+    /// In the order src/app.rs lists them, which is the order they're tried
+    /// in: "/resume" would be a section otherwise.
+    const PAGES: [Page; 4] = [
+        Page {
+            pattern: &RESUME,
+            found: |_| true,
+            // drawn from the same content as the main page
+            routes: |newest| {
+                vec![Route {
+                    path: "/resume".into(),
+                    lastmod: newest.clone(),
+                }]
+            },
+        },
+        Page {
+            pattern: &MAIN_PAGE,
+            found: |section| sections::from_param(section).is_some(),
+            // each section's route is the whole main page, which shows everything
+            routes: |newest| {
+                SECTIONS
+                    .iter()
+                    .map(|id| Route {
+                        path: sections::path(id),
+                        lastmod: newest.clone(),
+                    })
+                    .collect()
+            },
+        },
+        Page {
+            pattern: &PROJECT,
+            found: |id| id.and_then(find_project).is_some(),
+            routes: |_| project_routes().collect(),
+        },
+        Page {
+            pattern: &POST,
+            found: |slug| slug.and_then(find_post).is_some(),
+            routes: |_| {
+                POSTS
+                    .iter()
+                    .map(|p| Route {
+                        path: format!("/posts/{}", p.slug),
+                        lastmod: Some(p.date.to_string()),
+                    })
+                    .collect()
+            },
+        },
+    ];
+
+    fn project_routes() -> impl Iterator<Item = Route> {
+        PROJECTS.iter().filter(|p| !p.pinned).map(|p| Route {
+            path: format!("/projects/{}", p.id),
+            // the stats snapshot knows about pushes after the project started
+            lastmod: p
+                .stats
+                .map(|s| s.last_commit[..10].to_string())
+                .or(p.date.map(str::to_string)),
+        })
+    }
+
+    /// The résumé, main page sections, project pages and posts.
+    pub fn all() -> Vec<Route> {
+        let newest = project_routes()
+            .filter_map(|r| r.lastmod)
+            .chain(POSTS.iter().map(|p| p.date.to_string()))
+            .max();
+        PAGES
+            .iter()
+            .flat_map(|page| (page.routes)(&newest))
+            .collect()
+    }
+
+    /// https://www.sitemaps.org/protocol.html
+    pub fn sitemap() -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+        for route in all() {
+            out.push_str("  <url>\n");
+            writeln!(out, "    <loc>{}</loc>", escape(&SITE.url(&route.path))).unwrap();
+            if let Some(lastmod) = &route.lastmod {
+                writeln!(out, "    <lastmod>{lastmod}</lastmod>").unwrap();
+            }
+            out.push_str("  </url>\n");
+        }
+        out.push_str("</urlset>\n");
+        out
+    }
+
+    /// Crawlers only read robots.txt at the root of a host, so on a project page
+    /// like GitHub Pages' this one is a hint at most; the sitemap is what counts.
+    pub fn robots() -> String {
+        format!(
+            "User-agent: *\nAllow: /\n\nSitemap: {}\n",
+            SITE.url("/sitemap.xml")
+        )
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn locs() -> Vec<String> {
+            sitemap()
+                .lines()
+                .filter_map(|l| l.trim().strip_prefix("<loc>")?.strip_suffix("</loc>"))
+                .map(str::to_string)
+                .collect()
+        }
+
+        /// The page the app shows at `path`: the first pattern that matches
+        /// all of it, as the router picks, if that page finds its param.
+        fn page_at(path: &str) -> Option<usize> {
+            let (i, matched) = PAGES.iter().enumerate().find_map(|(i, page)| {
+                let matched = page.pattern.test(path)?;
+                matches!(matched.remaining(), "" | "/").then_some((i, matched))
+            })?;
+            let param = matched.params().into_iter().next().map(|(_, v)| v);
+            (PAGES[i].found)(param.as_deref()).then_some(i)
+        }
+
+        #[test]
+        fn every_page_is_served_by_its_own_route() {
+            for (i, page) in PAGES.iter().enumerate() {
+                for route in (page.routes)(&None) {
+                    assert_eq!(
+                        page_at(&route.path),
+                        Some(i),
+                        "{} isn't shown by the route that lists it",
+                        route.path
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn sitemap_lists_every_page() {
+            let locs = locs();
+            let pages = PAGES.iter().flat_map(|page| (page.routes)(&None));
+            for route in pages {
+                assert!(
+                    locs.contains(&SITE.url(&route.path)),
+                    "{} is missing from sitemap.xml",
+                    route.path
+                );
+            }
+        }
+
+        #[test]
+        fn sitemap_lists_only_pages_the_app_serves() {
+            for loc in locs() {
+                let path = loc
+                    .strip_prefix(SITE.base_url)
+                    .unwrap_or_else(|| panic!("{loc} is not under {}", SITE.base_url));
+                assert!(page_at(path).is_some(), "{path} would be a 404");
+            }
+        }
+
+        #[test]
+        fn robots_points_at_the_sitemap() {
+            assert!(robots().contains(&format!("Sitemap: {}/sitemap.xml", SITE.base_url)));
+        }
+    }
+}
@@ -133,3 +133,31 @@ p-4
 sm:transition sm:duration-300
 sm:hover:bg-white/8 sm:hover:border-white/22
 ";
+
+pub const CHIP: &str = "
+inline-flex items-center
+rounded-full
+border border-white/10
+bg-white/5
+px-3 py-1
+text-xs
+text-white/60
+backdrop-blur-none sm:backdrop-blur
+sm:transition sm:duration-300
+sm:hover:bg-white/10 sm:hover:text-white/85
+";
+
+pub const CHIP_ACTIVE: &str = "
+inline-flex items-center
+rounded-full
+border border-white/35
+bg-white/20
+px-3 py-1
+text-xs
+text-white
+backdrop-blur-none sm:backdrop-blur
+sm:transition sm:duration-300
+";
+
+/// Added for a moment to whatever the command palette jumped to.
+pub const HIGHLIGHT: [&str; 3] = ["ring-2", "ring-sky-300/60", "ring-offset-0"];
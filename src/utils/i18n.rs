@@ -0,0 +1,143 @@
+use leptos::prelude::*;
+
+// localStorage key holding the visitor's explicit choice
+const STORAGE_KEY: &str = "locale";
+
+/// Mirrors `LOCALES` in build/i18n.rs.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Pt,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Self::En, Self::Pt];
+
+    /// BCP 47 tag, also what gets stored and put on `<html lang>`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Pt => "pt",
+        }
+    }
+
+    /// "pt-BR" -> `Pt`
+    fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next()?.to_lowercase();
+        Self::ALL.into_iter().find(|l| l.code() == primary)
+    }
+
+    pub fn strings(self) -> &'static Strings {
+        match self {
+            Self::En => &EN,
+            Self::Pt => &PT,
+        }
+    }
+}
+
+/// A content string in every locale, generated from `Localized` fields in
+/// content/*.toml.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Text {
+    pub en: &'static str,
+    pub pt: &'static str,
+}
+
+impl Text {
+    pub const fn same(s: &'static str) -> Self {
+        Self { en: s, pt: s }
+    }
+
+    pub fn get(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::En => self.en,
+            Locale::Pt => self.pt,
+        }
+    }
+}
+
+/// The active locale, provided at the top of `App`. Reading through it is
+/// tracked, so views re-render when the visitor switches language.
+#[derive(Clone, Copy)]
+pub struct I18n(RwSignal<Locale>);
+
+impl I18n {
+    /// Starts out in the default locale, which is what prerendered pages are
+    /// in, so hydration sees the same text; call `detect` once mounted.
+    pub fn new() -> Self {
+        Self(RwSignal::new(Locale::default()))
+    }
+
+    /// Saved choice first, then the browser's preferred languages, then English.
+    pub fn detect(self) {
+        let locale = saved_locale().or_else(browser_locale).unwrap_or_default();
+        self.0.set(locale);
+        set_html_lang(locale);
+    }
+
+    pub fn locale(self) -> Locale {
+        self.0.get()
+    }
+
+    /// Switch language and remember it for the next visit.
+    pub fn set(self, locale: Locale) {
+        self.0.set(locale);
+        set_html_lang(locale);
+        if let Some(storage) = local_storage() {
+            let _ = storage.set_item(STORAGE_KEY, locale.code());
+        }
+    }
+
+    pub fn t(self) -> &'static Strings {
+        self.locale().strings()
+    }
+
+    pub fn text(self, text: Text) -> &'static str {
+        text.get(self.locale())
+    }
+}
+
+pub fn use_i18n() -> I18n {
+    expect_context::<I18n>()
+}
+
+/// Replace `{name}` placeholders: `fill(t.skills_used_in, &[("names", "dog")])`.
+pub fn fill(template: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(template.to_string(), |out, (name, value)| {
+            out.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+fn saved_locale() -> Option<Locale> {
+    let code = local_storage()?.get_item(STORAGE_KEY).ok()??;
+    Locale::from_tag(&code)
+}
+
+fn browser_locale() -> Option<Locale> {
+    let navigator = web_sys::window()?.navigator();
+    navigator
+        .languages()
+        .iter()
+        .filter_map(|tag| tag.as_string())
+        .chain(navigator.language())
+        .find_map(|tag| Locale::from_tag(&tag))
+}
+
+fn set_html_lang(locale: Locale) {
+    if let Some(html) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.document_element())
+    {
+        let _ = html.set_attribute("lang", locale.code());
+    }
+}
+
+// NOTE: catalogs live in content/i18n/; build/i18n.rs checks that every
+// locale has every key and generates `Strings`, `EN` and `PT`.
+include!(concat!(env!("OUT_DIR"), "/i18n_data.rs"));
@@ -0,0 +1,157 @@
+// schema.org structured data for each page, as JSON ready to go in a
+// <script type="application/ld+json">. Built from content/ by
+// build/json_ld.rs; `MAIN` is the main page's, with the `Person` the site is
+// about, and every other page's includes that `Person` too.
+
+/// A project page's, `None` for ids without a page.
+pub fn project(id: &str) -> Option<&'static str> {
+    PROJECTS
+        .iter()
+        .find(|(p, _)| *p == id)
+        .map(|(_, json)| *json)
+}
+
+pub fn post(slug: &str) -> Option<&'static str> {
+    POSTS
+        .iter()
+        .find(|(p, _)| *p == slug)
+        .map(|(_, json)| *json)
+}
+
+// NOTE: `MAIN`, `PROJECTS` and `POSTS` are generated by build/json_ld.rs
+include!(concat!(env!("OUT_DIR"), "/json_ld_data.rs"));
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::utils::posts_data::POSTS as ALL_POSTS;
+    use crate::utils::profile_data::PROFILE;
+    use crate::utils::projects_data::PROJECTS as ALL_PROJECTS;
+    use crate::utils::site_data::SITE;
+
+    fn parse(json: &str) -> Value {
+        serde_json::from_str(json).unwrap_or_else(|e| panic!("{e}: {json}"))
+    }
+
+    /// The graph's nodes, after checking the document around them.
+    fn graph(json: &str) -> Vec<Value> {
+        assert!(
+            !json.contains('<'),
+            "would need escaping in <script>: {json}"
+        );
+        let doc = parse(json);
+        assert_eq!(doc["@context"], "https://schema.org");
+        doc["@graph"].as_array().expect("@graph is a list").clone()
+    }
+
+    fn of_type<'a>(graph: &'a [Value], ty: &str) -> Vec<&'a Value> {
+        graph.iter().filter(|n| n["@type"] == ty).collect()
+    }
+
+    /// Every page has the one `Person`, and every other node's author is it.
+    fn person(graph: &[Value]) -> &Value {
+        let people = of_type(graph, "Person");
+        assert_eq!(people.len(), 1, "expected one Person in {graph:?}");
+        let person = people[0];
+        for node in graph.iter().filter(|n| n.get("author").is_some()) {
+            assert_eq!(node["author"]["@id"], person["@id"], "{node}");
+        }
+        person
+    }
+
+    fn all_pages() -> Vec<&'static str> {
+        let mut pages = vec![MAIN];
+        pages.extend(PROJECTS.iter().map(|(_, json)| *json));
+        pages.extend(POSTS.iter().map(|(_, json)| *json));
+        pages
+    }
+
+    #[test]
+    fn person_comes_from_the_profile() {
+        for json in all_pages() {
+            let graph = graph(json);
+            let person = person(&graph);
+            assert_eq!(person["name"], PROFILE.name);
+            assert_eq!(person["email"], PROFILE.mailto());
+            assert_eq!(person["url"], SITE.url("/"));
+
+            let same_as: Vec<&str> = person["sameAs"]
+                .as_array()
+                .expect("sameAs is a list")
+                .iter()
+                .filter_map(Value::as_str)
+                .collect();
+            let socials: Vec<&str> = PROFILE.socials.iter().map(|s| s.url).collect();
+            assert_eq!(same_as, socials);
+            for host in ["github.com", "linkedin.com"] {
+                assert!(
+                    same_as.iter().any(|url| url.contains(host)),
+                    "sameAs has no {host} link"
+                );
+            }
+        }
+    }
+
+    fn check_project(node: &Value, id: &str) {
+        let p = ALL_PROJECTS.iter().find(|p| p.id == id).unwrap();
+        assert_eq!(node["codeRepository"], p.repo_url);
+        assert_eq!(node["url"], SITE.url(&format!("/projects/{id}")));
+        assert_eq!(node["name"], p.name.en);
+        assert!(node["description"].is_string());
+    }
+
+    #[test]
+    fn every_project_page_is_software_source_code() {
+        let with_page: Vec<&str> = ALL_PROJECTS
+            .iter()
+            .filter(|p| !p.pinned)
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(
+            PROJECTS.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            with_page
+        );
+
+        for (id, json) in PROJECTS {
+            let graph = graph(json);
+            person(&graph);
+            let code = of_type(&graph, "SoftwareSourceCode");
+            assert_eq!(code.len(), 1, "{id}");
+            check_project(code[0], id);
+        }
+    }
+
+    #[test]
+    fn main_page_lists_the_site_and_every_project() {
+        let graph = graph(MAIN);
+        person(&graph);
+        let site = of_type(&graph, "WebSite");
+        assert_eq!(site.len(), 1);
+        assert_eq!(site[0]["url"], SITE.url("/"));
+
+        let code = of_type(&graph, "SoftwareSourceCode");
+        assert_eq!(code.len(), PROJECTS.len());
+        for (node, (id, _)) in code.iter().zip(PROJECTS) {
+            check_project(node, id);
+        }
+    }
+
+    #[test]
+    fn every_post_is_a_blog_posting() {
+        assert_eq!(POSTS.len(), ALL_POSTS.len());
+        for post in ALL_POSTS {
+            let graph = graph(super::post(post.slug).expect("post has JSON-LD"));
+            person(&graph);
+            let postings = of_type(&graph, "BlogPosting");
+            assert_eq!(postings.len(), 1, "{}", post.slug);
+            assert_eq!(postings[0]["headline"], post.title);
+            assert_eq!(postings[0]["datePublished"], post.date);
+            assert_eq!(
+                postings[0]["url"],
+                SITE.url(&format!("/posts/{}", post.slug))
+            );
+        }
+    }
+}
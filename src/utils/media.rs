@@ -0,0 +1,30 @@
+use crate::utils::site_data::SITE;
+
+pub fn svg_data_url(svg: &str) -> String {
+    let mut out = String::with_capacity(svg.len() + 32);
+    out.push_str("data:image/svg+xml;utf8,");
+    for c in svg.chars() {
+        match c {
+            '#' => out.push_str("%23"),
+            '&' => out.push_str("%26"),
+            '<' => out.push_str("%3C"),
+            '>' => out.push_str("%3E"),
+            '"' => out.push_str("%22"),
+            '\'' => out.push_str("%27"),
+            ' ' => out.push_str("%20"),
+            '\n' | '\r' | '\t' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Site path of a media file like "../assets/dog.png": "/assets/dog.png".
+pub fn site_path(src: &str) -> String {
+    format!("/{}", src.trim_start_matches("../").trim_start_matches('/'))
+}
+
+/// `site_path(src)` as a link, which works from a page at any depth.
+pub fn media_href(src: &str) -> String {
+    SITE.href(&site_path(src))
+}
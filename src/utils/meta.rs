@@ -0,0 +1,236 @@
+// <title>, description, canonical URL, Open Graph and Twitter card tags, and
+// schema.org JSON-LD for the page on screen. Pages describe themselves with
+// `use_page_meta`; `App` keeps <head> in sync with that on the client, and
+// the prerenderer writes the same tags into each page's HTML.
+
+use leptos::prelude::*;
+
+use crate::utils::json_ld;
+use crate::utils::profile_data::PROFILE;
+use crate::utils::site_data::SITE;
+
+/// Marks the <head> elements this module manages, so a page change can
+/// replace the previous page's (or the prerendered) ones.
+const MANAGED_ATTR: &str = "data-page-meta";
+
+const JSON_LD_TYPE: &str = "application/ld+json";
+
+#[derive(Clone, PartialEq)]
+pub struct PageMeta {
+    /// Goes before the site's title; `None` is just the site's title.
+    pub title: Option<String>,
+    pub description: String,
+    /// Site path like "/posts/x"; `None` for pages that shouldn't be indexed.
+    pub path: Option<String>,
+    /// Site path of a preview image.
+    pub image: Option<String>,
+    /// Site path of a preview video, like a project's demo recording.
+    pub video: Option<String>,
+    /// Open Graph type: "website", "article", ...
+    pub kind: &'static str,
+    /// Structured data from src/utils/json_ld.rs.
+    pub json_ld: Option<&'static str>,
+}
+
+impl PageMeta {
+    /// The site's own title and description, at `path`.
+    pub fn site(path: impl Into<String>) -> Self {
+        Self {
+            title: None,
+            description: SITE.description.to_string(),
+            path: Some(path.into()),
+            image: Some(SITE.og_image.to_string()),
+            video: None,
+            kind: "website",
+            json_ld: Some(json_ld::MAIN),
+        }
+    }
+
+    /// "Skills · Matheus - Dev Web Portfolio"
+    pub fn full_title(&self) -> String {
+        match &self.title {
+            Some(title) => format!("{title} · {}", SITE.title),
+            None => SITE.title.to_string(),
+        }
+    }
+
+    fn tags(&self) -> Vec<Tag> {
+        let title = self.title.clone().unwrap_or_else(|| SITE.title.to_string());
+        let mut tags = vec![
+            Tag::name("description", &self.description),
+            Tag::name("author", PROFILE.name),
+            Tag::property("og:site_name", SITE.title),
+            Tag::property("og:type", self.kind),
+            Tag::property("og:title", &title),
+            Tag::property("og:description", &self.description),
+            Tag::name("twitter:title", &title),
+            Tag::name("twitter:description", &self.description),
+        ];
+        match &self.path {
+            Some(path) => {
+                tags.push(Tag::link("canonical", &SITE.url(path)));
+                tags.push(Tag::property("og:url", &SITE.url(path)));
+            }
+            None => tags.push(Tag::name("robots", "noindex")),
+        }
+        if let Some(image) = &self.image {
+            tags.push(Tag::property("og:image", &SITE.url(image)));
+            tags.push(Tag::name("twitter:image", &SITE.url(image)));
+        }
+        if let Some(video) = &self.video {
+            tags.push(Tag::property("og:video", &SITE.url(video)));
+        }
+        let card = if self.image.is_some() {
+            "summary_large_image"
+        } else {
+            "summary"
+        };
+        tags.push(Tag::name("twitter:card", card));
+        tags
+    }
+
+    /// <title> and the tags above, for a prerendered page's <head>.
+    #[cfg(feature = "ssr")]
+    pub fn to_html(&self) -> String {
+        let mut out = format!("    <title>{}</title>\n", escape(&self.full_title()));
+        for tag in self.tags() {
+            out.push_str(&format!(
+                "    <{} {}=\"{}\" {}=\"{}\" {MANAGED_ATTR} />\n",
+                tag.element,
+                tag.key_attr,
+                tag.key,
+                tag.value_attr,
+                escape(&tag.value),
+            ));
+        }
+        if let Some(json) = self.json_ld {
+            // already safe inside <script>, see build/json_ld.rs
+            out.push_str(&format!(
+                "    <script type=\"{JSON_LD_TYPE}\" {MANAGED_ATTR}>{json}</script>\n"
+            ));
+        }
+        out
+    }
+
+    /// Swap the tags in <head> for this page's.
+    fn apply(&self) {
+        let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+            return;
+        };
+        let Some(head) = document.head() else {
+            return;
+        };
+        document.set_title(&self.full_title());
+
+        if let Ok(old) = head.query_selector_all(&format!("[{MANAGED_ATTR}]")) {
+            for i in 0..old.length() {
+                if let Some(node) = old.item(i)
+                    && let Some(parent) = node.parent_node()
+                {
+                    let _ = parent.remove_child(&node);
+                }
+            }
+        }
+        for tag in self.tags() {
+            let Ok(el) = document.create_element(tag.element) else {
+                continue;
+            };
+            let _ = el.set_attribute(tag.key_attr, tag.key);
+            let _ = el.set_attribute(tag.value_attr, &tag.value);
+            let _ = el.set_attribute(MANAGED_ATTR, "");
+            let _ = head.append_child(&el);
+        }
+        if let Some(json) = self.json_ld
+            && let Ok(el) = document.create_element("script")
+        {
+            let _ = el.set_attribute("type", JSON_LD_TYPE);
+            let _ = el.set_attribute(MANAGED_ATTR, "");
+            el.set_text_content(Some(json));
+            let _ = head.append_child(&el);
+        }
+    }
+}
+
+/// One <meta> or <link> element.
+struct Tag {
+    element: &'static str,
+    key_attr: &'static str,
+    key: &'static str,
+    value_attr: &'static str,
+    value: String,
+}
+
+impl Tag {
+    fn name(key: &'static str, value: &str) -> Self {
+        Self::meta("name", key, value)
+    }
+
+    /// Open Graph uses `property` rather than `name`.
+    fn property(key: &'static str, value: &str) -> Self {
+        Self::meta("property", key, value)
+    }
+
+    fn meta(key_attr: &'static str, key: &'static str, value: &str) -> Self {
+        Self {
+            element: "meta",
+            key_attr,
+            key,
+            value_attr: "content",
+            value: value.to_string(),
+        }
+    }
+
+    fn link(rel: &'static str, href: &str) -> Self {
+        Self {
+            element: "link",
+            key_attr: "rel",
+            key: rel,
+            value_attr: "href",
+            value: href.to_string(),
+        }
+    }
+}
+
+/// The metadata of the page on screen, provided at the top of `App`.
+#[derive(Clone, Copy)]
+pub struct Head(RwSignal<PageMeta>);
+
+impl Head {
+    /// Provides the prerenderer's `Head` if it set one up (so it can read
+    /// what the page set afterwards), a new one otherwise.
+    pub fn provide() -> Self {
+        let head = use_context::<Head>().unwrap_or_else(Head::new);
+        provide_context(head);
+        // effects only run in the browser
+        Effect::new(move || head.0.with(PageMeta::apply));
+        head
+    }
+
+    pub fn new() -> Self {
+        Self(RwSignal::new(PageMeta::site("/")))
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn get(self) -> PageMeta {
+        self.0.get_untracked()
+    }
+}
+
+/// Make `meta` the page's metadata, updating it when what it reads changes
+/// (e.g. the language).
+pub fn use_page_meta(meta: impl Fn() -> PageMeta + Send + Sync + 'static) {
+    let head = expect_context::<Head>();
+    // right away for the prerenderer, which runs no effects
+    head.0.set(meta());
+    Effect::new(move || head.0.set(meta()));
+}
+
+/// Escape text for HTML element content and attribute values.
+#[cfg(any(feature = "ssr", test))]
+pub fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -1,4 +1,18 @@
 pub mod glass;
+pub mod i18n;
+pub mod json_ld;
 pub mod macros;
+pub mod media;
+pub mod meta;
+pub mod posts_data;
+pub mod profile_data;
+pub mod project_filter;
 pub mod projects_data;
+pub mod resume_data;
+pub mod scroll;
+pub mod search;
+pub mod sections;
+#[cfg(not(feature = "ssr"))]
+pub mod service_worker;
+pub mod site_data;
 pub mod skills_data;
@@ -0,0 +1,38 @@
+use chrono::Datelike;
+
+/// A post from content/posts/, rendered to HTML by the build.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Post {
+    pub slug: &'static str,
+    pub title: &'static str,
+    pub date: &'static str, // "YYYY-MM-DD", checked by the build
+    pub tags: &'static [&'static str],
+    pub summary: &'static str,
+    pub reading_minutes: usize,
+    pub toc: &'static [TocEntry], // `##` and `###` headings
+    pub html: &'static str,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct TocEntry {
+    pub level: u8,
+    pub id: &'static str, // heading element id
+    pub title: &'static str,
+}
+
+impl Post {
+    /// "18 Oct 2026", with month names from `date.months` in the catalog.
+    pub fn display_date(&self, months: &[&str]) -> String {
+        chrono::NaiveDate::parse_from_str(self.date, "%Y-%m-%d")
+            .map(|d| format!("{} {} {}", d.day(), months[d.month0() as usize], d.year()))
+            .unwrap_or_default()
+    }
+}
+
+pub fn find_post(slug: &str) -> Option<&'static Post> {
+    POSTS.iter().find(|p| p.slug == slug)
+}
+
+// NOTE: `POSTS` (newest first) is generated by build/posts.rs from the
+// Markdown files in content/posts/.
+include!(concat!(env!("OUT_DIR"), "/posts_data.rs"));
@@ -0,0 +1,39 @@
+use crate::utils::i18n::Text;
+
+/// Who the site is about, from content/profile.toml. UI strings that mention
+/// these get them filled in at build time, see build/profile.rs.
+#[derive(Clone, Copy)]
+pub struct Profile {
+    /// Full name, for bylines and metadata.
+    pub name: &'static str,
+    pub first_name: &'static str,
+    pub headline: Text,
+    pub role: Text,
+    pub email: &'static str,
+    pub location: Option<Text>,
+    pub languages: &'static [SpokenLanguage],
+    pub socials: &'static [Social],
+}
+
+#[derive(Clone, Copy)]
+pub struct SpokenLanguage {
+    pub name: Text,
+    /// "native", "advanced", ...
+    pub level: Text,
+}
+
+#[derive(Clone, Copy)]
+pub struct Social {
+    pub label: &'static str,
+    pub url: &'static str,
+    pub icon_svg: &'static str,
+}
+
+impl Profile {
+    pub fn mailto(&self) -> String {
+        format!("mailto:{}", self.email)
+    }
+}
+
+// NOTE: `PROFILE` is generated by build/profile.rs
+include!(concat!(env!("OUT_DIR"), "/profile_data.rs"));
@@ -0,0 +1,238 @@
+use wasm_bindgen::JsValue;
+use web_sys::UrlSearchParams;
+
+use crate::utils::i18n::{Locale, Strings};
+use crate::utils::projects_data::{Project, ProjectStatus};
+use crate::utils::skills_data::skills;
+
+// Query keys, repeated once per selected value: ?lang=rust&lang=python&sort=newest
+const LANG_KEY: &str = "lang";
+const TAG_KEY: &str = "tag";
+const STATUS_KEY: &str = "status";
+const SORT_KEY: &str = "sort";
+const SKILL_KEY: &str = "skill";
+
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortMode {
+    #[default]
+    Featured,
+    Newest,
+    Name,
+}
+
+impl SortMode {
+    pub const ALL: [SortMode; 3] = [Self::Featured, Self::Newest, Self::Name];
+
+    pub fn label(self, t: &Strings) -> &'static str {
+        match self {
+            Self::Featured => t.sort_featured,
+            Self::Newest => t.sort_newest,
+            Self::Name => t.sort_name,
+        }
+    }
+
+    fn slug(self) -> &'static str {
+        match self {
+            Self::Featured => "featured",
+            Self::Newest => "newest",
+            Self::Name => "name",
+        }
+    }
+
+    fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.slug() == slug)
+    }
+}
+
+/// Chip selection for the Projects section. Chips in the same row are OR'd,
+/// rows are AND'd, and an empty row doesn't filter at all.
+#[derive(Clone, PartialEq, Default)]
+pub struct ProjectFilter {
+    pub languages: Vec<&'static str>,
+    pub tags: Vec<&'static str>,
+    pub statuses: Vec<ProjectStatus>,
+    /// Set from the Skills section: only projects exercising this skill.
+    pub skill: Option<&'static str>,
+    pub sort: SortMode,
+}
+
+impl ProjectFilter {
+    pub fn is_empty(&self) -> bool {
+        self.languages.is_empty()
+            && self.tags.is_empty()
+            && self.statuses.is_empty()
+            && self.skill.is_none()
+    }
+
+    pub fn clear(&mut self) {
+        self.languages.clear();
+        self.tags.clear();
+        self.statuses.clear();
+        self.skill = None;
+    }
+
+    /// Show exactly the projects backing `skill`.
+    pub fn only_skill(&mut self, skill: &'static str) {
+        self.clear();
+        self.skill = Some(skill);
+    }
+
+    pub fn matches(&self, p: &Project) -> bool {
+        let lang_ok = self.languages.is_empty() || self.languages.contains(&p.language_label);
+        let tag_ok = self.tags.is_empty() || p.tags.iter().any(|t| self.tags.contains(t));
+        let status_ok =
+            self.statuses.is_empty() || p.status.is_some_and(|s| self.statuses.contains(&s));
+
+        let skill_ok = self.skill.is_none_or(|s| p.skills.contains(&s));
+
+        lang_ok && tag_ok && status_ok && skill_ok
+    }
+
+    /// Filter and sort `projects`; pinned entries skip both and go last.
+    /// `locale` decides the name order.
+    pub fn apply(&self, projects: &[Project], locale: Locale) -> Vec<Project> {
+        let (pinned, rest): (Vec<Project>, Vec<Project>) =
+            projects.iter().copied().partition(|p| p.pinned);
+
+        let mut out: Vec<Project> = rest.into_iter().filter(|p| self.matches(p)).collect();
+
+        // sort_by is stable, so ties keep content/projects.toml order
+        match self.sort {
+            SortMode::Featured => out.sort_by_key(|p| !p.featured),
+            SortMode::Newest => out.sort_by(|a, b| b.date.cmp(&a.date)),
+            SortMode::Name => out.sort_by_key(|p| p.name.get(locale).to_lowercase()),
+        }
+
+        out.extend(pinned);
+        out
+    }
+
+    /// Read the selection from `location.search`, ignoring unknown values.
+    pub fn from_location(projects: &[Project]) -> Self {
+        let search = web_sys::window()
+            .and_then(|w| w.location().search().ok())
+            .unwrap_or_default();
+        let Ok(params) = UrlSearchParams::new_with_str(&search) else {
+            return Self::default();
+        };
+
+        let all = |key: &str| -> Vec<String> {
+            params
+                .get_all(key)
+                .iter()
+                .filter_map(|v| v.as_string())
+                .map(|v| v.to_lowercase())
+                .collect()
+        };
+        let pick = |wanted: Vec<String>, options: Vec<&'static str>| -> Vec<&'static str> {
+            options
+                .into_iter()
+                .filter(|o| wanted.contains(&o.to_lowercase()))
+                .collect()
+        };
+
+        Self {
+            languages: pick(all(LANG_KEY), languages(projects)),
+            tags: pick(all(TAG_KEY), tags(projects)),
+            statuses: all(STATUS_KEY)
+                .iter()
+                .filter_map(|s| ProjectStatus::from_slug(s))
+                .collect(),
+            skill: params
+                .get(SKILL_KEY)
+                .and_then(|wanted| skills().find(|s| s.id == wanted))
+                .map(|s| s.name),
+            sort: params
+                .get(SORT_KEY)
+                .and_then(|s| SortMode::from_slug(&s))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Mirror the selection into the URL without adding a history entry.
+    /// Other query parameters and the hash are left alone.
+    pub fn write_location(&self) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let location = window.location();
+        let search = location.search().unwrap_or_default();
+        let Ok(params) = UrlSearchParams::new_with_str(&search) else {
+            return;
+        };
+
+        for key in [LANG_KEY, TAG_KEY, STATUS_KEY, SKILL_KEY, SORT_KEY] {
+            params.delete(key);
+        }
+        for lang in &self.languages {
+            params.append(LANG_KEY, &lang.to_lowercase());
+        }
+        for tag in &self.tags {
+            params.append(TAG_KEY, &tag.to_lowercase());
+        }
+        for status in &self.statuses {
+            params.append(STATUS_KEY, status.slug());
+        }
+        if let Some(skill) = self
+            .skill
+            .and_then(|name| skills().find(|s| s.name == name))
+        {
+            params.append(SKILL_KEY, skill.id);
+        }
+        if self.sort != SortMode::default() {
+            params.append(SORT_KEY, self.sort.slug());
+        }
+
+        let query = String::from(params.to_string());
+        let path = location.pathname().unwrap_or_default();
+        let hash = location.hash().unwrap_or_default();
+        let url = if query.is_empty() {
+            format!("{path}{hash}")
+        } else {
+            format!("{path}?{query}{hash}")
+        };
+
+        if search.trim_start_matches('?') != query
+            && let Ok(history) = window.history()
+        {
+            let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url));
+        }
+    }
+}
+
+/// Add `value` if missing, remove it otherwise.
+pub fn toggle<T: PartialEq>(list: &mut Vec<T>, value: T) {
+    if let Some(i) = list.iter().position(|v| *v == value) {
+        list.remove(i);
+    } else {
+        list.push(value);
+    }
+}
+
+/// Distinct language labels, in content order.
+pub fn languages(projects: &[Project]) -> Vec<&'static str> {
+    distinct(projects.iter().map(|p| p.language_label))
+}
+
+/// Distinct tags, in content order.
+pub fn tags(projects: &[Project]) -> Vec<&'static str> {
+    distinct(projects.iter().flat_map(|p| p.tags.iter().copied()))
+}
+
+/// Statuses actually used by some project.
+pub fn statuses(projects: &[Project]) -> Vec<ProjectStatus> {
+    ProjectStatus::ALL
+        .into_iter()
+        .filter(|s| projects.iter().any(|p| p.status == Some(*s)))
+        .collect()
+}
+
+fn distinct(values: impl Iterator<Item = &'static str>) -> Vec<&'static str> {
+    let mut out = Vec::new();
+    for v in values {
+        if !v.is_empty() && !out.contains(&v) {
+            out.push(v);
+        }
+    }
+    out
+}
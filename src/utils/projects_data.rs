@@ -1,117 +1,136 @@
-#[derive(Clone, Copy)]
+use chrono::Datelike;
+
+use crate::utils::i18n::{Strings, Text};
+
+#[derive(Clone, Copy, PartialEq)]
 pub struct Project {
     pub id: &'static str,
-    pub name: &'static str,
-    pub description: &'static str,
+    pub name: Text,
+    pub description: Text,
 
     pub language_label: &'static str,
     pub language_svg: Option<&'static str>,
 
+    pub tags: &'static [&'static str],
+    pub skills: &'static [&'static str], // SkillItem names
+    pub status: Option<ProjectStatus>,
+    pub date: Option<&'static str>, // "YYYY-MM"
+    pub featured: bool,
+    pub pinned: bool, // always last, ignores filters (e.g. "more")
+
     pub repo_url: &'static str,
-    pub media_src: &'static str,
-    pub media_label: &'static str,
+    pub media: Media,
     pub demo_url: &'static str,
-    pub demo_label: &'static str,
-    pub cta_label: &'static str,
+    pub demo_label: Text,
+    pub cta_label: Text,
+
+    pub case_study: Option<&'static CaseStudy>,
+    pub stats: Option<RepoStats>, // content/repo_stats.json snapshot
+    pub og_image: Option<&'static str>, // link preview, see build/og_images.rs
+}
+
+/// GitHub numbers as of the last scripts/fetch_repo_stats.sh run.
+#[derive(Clone, Copy, PartialEq)]
+pub struct RepoStats {
+    pub stars: u32,
+    pub last_commit: &'static str, // RFC 3339, checked by the build
+    pub languages: &'static [&'static str], // most-used first
+    pub archived: bool,
+}
+
+impl RepoStats {
+    /// "Nov 2025", with month names from `date.months` in the catalog.
+    pub fn last_commit_month(&self, months: &[&str]) -> String {
+        chrono::DateTime::parse_from_rfc3339(self.last_commit)
+            .map(|d| format!("{} {}", months[d.month0() as usize], d.year()))
+            .unwrap_or_default()
+    }
+}
+
+/// Long-form write-up shown on /projects/:id
+#[derive(Clone, Copy, PartialEq)]
+pub struct CaseStudy {
+    pub problem: &'static [&'static str],
+    pub architecture: &'static [&'static str],
+    pub challenges: &'static [&'static str],
+    pub results: &'static [&'static str],
+    pub media: &'static [Media],
+    pub links: &'static [Link],
+}
+
+/// A recording or screenshot, in whichever renditions it has (paths like
+/// "../assets/x.webm"); the build checks they exist.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Media {
+    pub label: &'static str,
+    pub webm: Option<&'static str>,
+    pub mp4: Option<&'static str>,
+    pub gif: Option<&'static str>,
+    /// A still: the video's first frame, or the screenshot itself.
+    pub poster: Option<&'static str>,
+}
+
+impl Media {
+    pub fn is_video(&self) -> bool {
+        self.webm.is_some() || self.mp4.is_some()
+    }
+
+    /// What to show where there's no video, or it can't play.
+    pub fn image(&self) -> Option<&'static str> {
+        self.gif.or(self.poster)
+    }
+
+    /// `<source>`s in order of preference, with their types.
+    pub fn sources(&self) -> impl Iterator<Item = (&'static str, &'static str)> {
+        [(self.webm, "video/webm"), (self.mp4, "video/mp4")]
+            .into_iter()
+            .filter_map(|(src, mime)| Some((src?, mime)))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Link {
+    pub label: &'static str,
+    pub url: &'static str,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProjectStatus {
+    Active,
+    Complete,
+    Archived,
+}
+
+impl ProjectStatus {
+    pub const ALL: [ProjectStatus; 3] = [Self::Active, Self::Complete, Self::Archived];
+
+    pub fn label(self, t: &Strings) -> &'static str {
+        match self {
+            Self::Active => t.status_active,
+            Self::Complete => t.status_complete,
+            Self::Archived => t.status_archived,
+        }
+    }
+
+    /// Value used in the `?status=` query parameter.
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Complete => "complete",
+            Self::Archived => "archived",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.slug() == slug)
+    }
+}
+
+/// Projects that get a /projects/:id page; pinned entries are just links.
+pub fn find_project(id: &str) -> Option<&'static Project> {
+    PROJECTS.iter().find(|p| p.id == id && !p.pinned)
 }
 
-// NOTE: this file lives in src/utils/, assets are in src/assets/
-pub const RUST_SVG: &str = include_str!("../assets/rust.svg");
-pub const JAVA_SVG: &str = include_str!("../assets/java.svg");
-// pub const C_SVG: &str = include_str!("../assets/c.svg");
-pub const PYTHON_SVG: &str = include_str!("../assets/python.svg");
-pub const DJANGO_SVG: &str = include_str!("../assets/django.svg");
-
-pub const PROJECTS: &[Project] = &[
-    Project {
-        id: "obsctl",
-        name: "obsctl",
-        description: "High-performance tool for managing cloud object storage, built with a focus on reliability, speed, and efficient handling of large data workflows, including uploads, downloads, and large-scale object management.",
-        language_label: "Rust",
-        language_svg: Some(RUST_SVG),
-        repo_url: "https://github.com/araujoviana/obsctl",
-        media_src: "../assets/obsctl.webm",
-        media_label: "obsctl terminal demo",
-        demo_url: "",
-        demo_label: "Demo",
-        cta_label: "GitHub",
-    },
-    Project {
-        id: "dog",
-        name: "dog",
-        description: "Local-first AI system for retrieval-augmented generation (RAG) over personal documents and audio, combining OCR, semantic search, and conversational querying while keeping all data private and on-device.",
-        language_label: "Python",
-        language_svg: Some(PYTHON_SVG),
-        repo_url: "https://github.com/araujoviana/dog",
-        media_src: "../assets/dog.png",
-        media_label: "index page for dog",
-        demo_url: "",
-        demo_label: "Demo",
-        cta_label: "GitHub",
-    },
-    Project {
-        id: "an-cli",
-        name: "an-cli",
-        description: "Numerical analysis toolkit implementing root finding, numerical differentiation, integration, interpolation, and least-squares regression, built for reproducible and scriptable computation workflows.",
-        language_label: "Python",
-        language_svg: Some(PYTHON_SVG),
-        repo_url: "https://github.com/araujoviana/an-cli",
-        media_src: "../assets/an-cli.webm",
-        media_label: "an-cli terminal demo",
-        demo_url: "",
-        demo_label: "Demo",
-        cta_label: "GitHub",
-    },
-    Project {
-        id: "file-manager",
-        name: "file-manager",
-        description: "Lightweight graphical file manager focused on filesystem navigation, metadata inspection, and system-level file handling in a minimal desktop interface.",
-        language_label: "Python",
-        language_svg: Some(PYTHON_SVG),
-        repo_url: "https://github.com/araujoviana/file-manager",
-        media_src: "../assets/file-manager.webm",
-        media_label: "placeholder: screenshot",
-        demo_url: "",
-        demo_label: "Demo",
-        cta_label: "GitHub",
-    },
-    Project {
-        id: "assembly-interpreter",
-        name: "Assembly Interpreter",
-        description: "Interpreter for a custom assembly-inspired language, built around a REPL-driven workflow with registers, memory buffers, control flow instructions, and program load/save support.",
-        language_label: "Java",
-        language_svg: Some(JAVA_SVG),
-        repo_url: "https://github.com/araujoviana/proj-assembly-estrutura-dados",
-        media_src: "../assets/assembly.webm",
-        media_label: "assembly interpreter demo",
-        demo_url: "",
-        demo_label: "Demo",
-        cta_label: "GitHub",
-    },
-    Project {
-        id: "copperknight",
-        name: "CopperKnight",
-        description: "Full-stack web platform for creating and sharing digital artwork directly in the browser, featuring a custom drawing canvas, persistent storage, user accounts, and social discovery backed by a SQL database-driven backend.",
-        language_label: "Django",
-        language_svg: Some(DJANGO_SVG),
-        repo_url: "https://github.com/araujoviana/copperknight",
-        media_src: "../assets/copperknight.webm",
-        media_label: "placeholder: screenshots",
-        demo_url: "",
-        demo_label: "Demo",
-        cta_label: "GitHub",
-    },
-    Project {
-        id: "more",
-        name: "See more projects",
-        description: "Browse my full repository list on GitHub!",
-        language_label: "",
-        language_svg: None,
-        repo_url: "https://github.com/araujoviana?tab=repositories",
-        media_src: "../assets/gh.png",
-        media_label: "placeholder: github.png",
-        demo_url: "",
-        demo_label: "",
-        cta_label: "Open GitHub",
-    },
-];
+// NOTE: the table itself lives in content/projects.toml; build/content.rs
+// validates it and generates `PROJECTS` plus one `*_SVG` const per icon used.
+include!(concat!(env!("OUT_DIR"), "/projects_data.rs"));
@@ -0,0 +1,29 @@
+use crate::utils::skills_data::Proficiency;
+
+/// What /resume and cv.pdf show, from content/resume.toml.
+#[derive(Clone, Copy)]
+pub struct Resume {
+    pub sections: &'static [ResumeSection],
+    pub projects: &'static [&'static str], // Project ids, in order
+    /// Skills below this are left out.
+    pub min_proficiency: Option<Proficiency>,
+    /// Site path of the PDF build/resume.rs writes.
+    pub pdf: &'static str,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ResumeSection {
+    Summary,
+    Skills,
+    Projects,
+    Languages,
+}
+
+impl Resume {
+    pub fn shows_skill(&self, proficiency: Proficiency) -> bool {
+        self.min_proficiency.is_none_or(|min| proficiency >= min)
+    }
+}
+
+// NOTE: `RESUME` is generated by build/resume.rs
+include!(concat!(env!("OUT_DIR"), "/resume_data.rs"));
@@ -0,0 +1,106 @@
+use std::cell::Cell;
+use std::time::Duration;
+
+use leptos::prelude::{request_animation_frame, set_timeout};
+use web_sys::{
+    Element, ScrollBehavior, ScrollIntoViewOptions, ScrollLogicalPosition, ScrollToOptions,
+};
+
+use crate::utils::glass::HIGHLIGHT;
+
+/// Frames to wait for a target that a route change hasn't rendered yet.
+const REVEAL_FRAMES: u32 = 30;
+
+// Scroll offset of the main page when the user left it for a sub-page, and
+// whether the next visit is positioned by whoever navigates there instead.
+thread_local! {
+    static SAVED_Y: Cell<Option<f64>> = const { Cell::new(None) };
+    static SKIP_LANDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Remember where the main page was scrolled to before navigating away.
+pub fn remember() {
+    if let Some(win) = web_sys::window() {
+        SAVED_Y.with(|y| y.set(win.scroll_y().ok()));
+    }
+}
+
+/// Drop the remembered offset, e.g. when the next visit jumps somewhere else.
+pub fn forget() {
+    SAVED_Y.with(|y| y.set(None));
+}
+
+/// The caller will scroll the main page it's about to open, so it shouldn't
+/// jump to the section in the URL (or a remembered offset) first.
+pub fn skip_landing() {
+    forget();
+    SKIP_LANDING.with(|s| s.set(true));
+}
+
+/// Consume the `skip_landing` request, if any.
+pub fn take_skip_landing() -> bool {
+    SKIP_LANDING.with(Cell::take)
+}
+
+pub fn has_saved() -> bool {
+    SAVED_Y.with(|y| y.get().is_some())
+}
+
+/// Jump back to the remembered offset (once), skipping smooth scrolling.
+pub fn restore() {
+    let Some(y) = SAVED_Y.with(Cell::take) else {
+        return;
+    };
+    // wait a frame so the sections have been laid out
+    request_animation_frame(move || jump_to(y));
+}
+
+pub fn jump_to(y: f64) {
+    if let Some(win) = web_sys::window() {
+        let opts = ScrollToOptions::new();
+        opts.set_top(y);
+        opts.set_behavior(ScrollBehavior::Instant);
+        win.scroll_to_with_scroll_to_options(&opts);
+    }
+}
+
+/// Smooth-scroll element `id` to the middle of the screen and flash it.
+/// Calls `on_missing` if it still isn't in the DOM after a few frames.
+pub fn reveal(id: String, on_missing: impl FnOnce() + 'static) {
+    reveal_within(id, REVEAL_FRAMES, Box::new(on_missing));
+}
+
+fn reveal_within(id: String, frames: u32, on_missing: Box<dyn FnOnce()>) {
+    let el = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(&id));
+
+    match el {
+        Some(el) => {
+            let opts = ScrollIntoViewOptions::new();
+            opts.set_behavior(ScrollBehavior::Smooth);
+            opts.set_block(ScrollLogicalPosition::Center);
+            el.scroll_into_view_with_scroll_into_view_options(&opts);
+            flash(el);
+        }
+        None if frames > 0 => {
+            request_animation_frame(move || reveal_within(id, frames - 1, on_missing))
+        }
+        None => on_missing(),
+    }
+}
+
+fn flash(el: Element) {
+    let classes = el.class_list();
+    for c in HIGHLIGHT {
+        let _ = classes.add_1(c);
+    }
+    set_timeout(
+        move || {
+            for c in HIGHLIGHT {
+                let _ = classes.remove_1(c);
+            }
+        },
+        Duration::from_millis(1600),
+    );
+}
@@ -0,0 +1,246 @@
+use crate::utils::i18n::{EN, Locale, PT, Strings, Text};
+
+/// One thing the command palette can find. Everything here is `'static` data,
+/// so there is no index to build when the page loads.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SearchEntry {
+    pub title: Text,
+    pub detail: Text,
+    /// Extra lowercase words, in every locale, that match but aren't shown.
+    pub keywords: &'static str,
+    pub target: Target,
+}
+
+/// Where picking an entry takes you on the main page.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Target {
+    Section(&'static str), // section element id
+    Project(&'static str), // project id
+    Skill(&'static str),   // SkillItem id
+    Post(&'static str),    // post slug, opens /posts/:slug
+    About(&'static str),   // element id inside #about
+}
+
+impl Target {
+    pub fn label(self, t: &Strings) -> &'static str {
+        match self {
+            Self::Section(_) => t.palette_section,
+            Self::Project(_) => t.palette_project,
+            Self::Skill(_) => t.palette_skill,
+            Self::Post(_) => t.palette_post,
+            Self::About(_) => t.palette_about,
+        }
+    }
+
+    /// Id of the element to scroll to and highlight.
+    pub fn element_id(self) -> String {
+        match self {
+            Self::Section(id) | Self::About(id) => id.to_string(),
+            Self::Project(id) => format!("project-{id}"),
+            Self::Skill(id) => format!("skill-{id}"),
+            Self::Post(slug) => format!("post-{slug}"),
+        }
+    }
+
+    /// Tie-breaker so "rust" lists the skill before a paragraph mentioning it.
+    fn weight(self) -> u32 {
+        match self {
+            Self::Section(_) => 6,
+            Self::Project(_) => 4,
+            Self::Skill(_) | Self::Post(_) => 2,
+            Self::About(_) => 0,
+        }
+    }
+}
+
+const SECTIONS: &[SearchEntry] = &[
+    section(
+        Text {
+            en: EN.nav_home,
+            pt: PT.nav_home,
+        },
+        "home",
+    ),
+    section(
+        Text {
+            en: EN.nav_skills,
+            pt: PT.nav_skills,
+        },
+        "skills",
+    ),
+    section(
+        Text {
+            en: EN.nav_projects,
+            pt: PT.nav_projects,
+        },
+        "projects",
+    ),
+    section(
+        Text {
+            en: EN.nav_posts,
+            pt: PT.nav_posts,
+        },
+        "posts",
+    ),
+    section(
+        Text {
+            en: EN.nav_contact,
+            pt: PT.nav_contact,
+        },
+        "contact",
+    ),
+    section(
+        Text {
+            en: EN.nav_about,
+            pt: PT.nav_about,
+        },
+        "about",
+    ),
+];
+
+const ABOUT_ME: Text = Text {
+    en: EN.palette_about_me,
+    pt: PT.palette_about_me,
+};
+
+const ABOUT_TEXT: &[SearchEntry] = &[
+    SearchEntry {
+        title: ABOUT_ME,
+        detail: Text {
+            en: EN.about_p1,
+            pt: PT.about_p1,
+        },
+        keywords: "",
+        target: Target::About("about-p1"),
+    },
+    SearchEntry {
+        title: ABOUT_ME,
+        detail: Text {
+            en: EN.about_p2,
+            pt: PT.about_p2,
+        },
+        keywords: "",
+        target: Target::About("about-p2"),
+    },
+];
+
+const fn section(title: Text, id: &'static str) -> SearchEntry {
+    SearchEntry {
+        title,
+        detail: Text::same(""),
+        keywords: "",
+        target: Target::Section(id),
+    }
+}
+
+/// Every entry: sections, projects, skills and posts (generated from
+/// content/), the About paragraphs, and one per command/output pair of the
+/// About terminal.
+fn entries() -> impl Iterator<Item = SearchEntry> {
+    // the build guarantees both scripts have the same number of lines
+    let script: Vec<Text> = EN
+        .about_terminal
+        .iter()
+        .zip(PT.about_terminal)
+        .map(|(en, pt)| Text { en, pt })
+        .collect();
+    let terminal = script
+        .split(|line| line.en.is_empty())
+        .filter_map(|chunk| match *chunk {
+            [command, output, ..] => Some(SearchEntry {
+                title: output,
+                detail: command,
+                keywords: "",
+                target: Target::About("about-terminal"),
+            }),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    SECTIONS
+        .iter()
+        .chain(CONTENT_ENTRIES)
+        .chain(ABOUT_TEXT)
+        .copied()
+        .chain(terminal)
+}
+
+/// Best `limit` matches for `query` in `locale`, or the section list when
+/// it's blank. Every whitespace-separated word has to match somewhere.
+pub fn search(query: &str, locale: Locale, limit: usize) -> Vec<SearchEntry> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if words.is_empty() {
+        return SECTIONS.to_vec();
+    }
+
+    let mut hits: Vec<(u32, SearchEntry)> = entries()
+        .filter_map(|e| score(&e, locale, &words).map(|s| (s, e)))
+        .collect();
+    // stable, so equal scores keep index order
+    hits.sort_by(|(a, ea), (b, eb)| {
+        let len = |e: &SearchEntry| e.title.get(locale).len();
+        b.cmp(a).then(len(ea).cmp(&len(eb)))
+    });
+
+    hits.into_iter().take(limit).map(|(_, e)| e).collect()
+}
+
+fn score(entry: &SearchEntry, locale: Locale, words: &[String]) -> Option<u32> {
+    let title = entry.title.get(locale).to_lowercase();
+    let detail = entry.detail.get(locale).to_lowercase();
+
+    let mut total = entry.target.weight();
+    for w in words {
+        total += title_score(&title, w).or_else(|| {
+            (detail.contains(w.as_str()) || entry.keywords.contains(w.as_str())).then_some(10)
+        })?;
+    }
+    Some(total)
+}
+
+fn title_score(title: &str, word: &str) -> Option<u32> {
+    if title == word {
+        Some(100)
+    } else if title.starts_with(word) {
+        Some(80)
+    } else if title
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|part| part.starts_with(word))
+    {
+        Some(60)
+    } else if title.contains(word) {
+        Some(40)
+    } else if word.len() > 1 {
+        // "fmgr" -> "file-manager"; letters scattered across the whole title
+        // are more likely noise than a match
+        let max_gaps = 2 * word.chars().count() as u32;
+        fuzzy_gaps(title, word)
+            .filter(|&gaps| gaps <= max_gaps)
+            .map(|gaps| 30 - gaps.min(29))
+    } else {
+        None
+    }
+}
+
+/// Characters skipped while finding `word` as a subsequence of `text`.
+fn fuzzy_gaps(text: &str, word: &str) -> Option<u32> {
+    let mut wanted = word.chars().peekable();
+    let mut gaps = 0;
+    let mut started = false;
+
+    for c in text.chars() {
+        match wanted.peek() {
+            None => break,
+            Some(&w) if w == c => {
+                wanted.next();
+                started = true;
+            }
+            Some(_) if started => gaps += 1,
+            Some(_) => {}
+        }
+    }
+    wanted.peek().is_none().then_some(gaps)
+}
+
+// NOTE: `CONTENT_ENTRIES` (projects, skills and posts) is generated by build/search.rs
+include!(concat!(env!("OUT_DIR"), "/search_index.rs"));
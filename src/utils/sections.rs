@@ -0,0 +1,74 @@
+use web_sys::{ScrollBehavior, ScrollIntoViewOptions, ScrollLogicalPosition};
+
+use crate::utils::site_data::SITE;
+
+/// Element ids of the main page's sections, top to bottom. Each one is also
+/// its route: `/skills`, `/projects`, ... and `/` for the first.
+pub const SECTIONS: [&str; 6] = ["home", "skills", "projects", "posts", "contact", "about"];
+
+/// How far down the viewport a section's top has to be to count as current.
+const SPY_LINE: f64 = 0.4;
+
+/// "/skills" for `skills`, "/" for `home`.
+pub fn path(id: &str) -> String {
+    if id == SECTIONS[0] {
+        "/".into()
+    } else {
+        format!("/{id}")
+    }
+}
+
+/// `path(id)` as a link, under the site's base path.
+pub fn href(id: &str) -> String {
+    SITE.href(&path(id))
+}
+
+/// The section for the `:section` route param; `None` means a 404.
+pub fn from_param(param: Option<&str>) -> Option<&'static str> {
+    match param {
+        None => Some(SECTIONS[0]),
+        // "/home" isn't a thing, "/" is
+        Some(p) => SECTIONS[1..].iter().copied().find(|s| *s == p),
+    }
+}
+
+/// Whether `pathname` is the main page, at any section.
+pub fn is_main_page(pathname: &str) -> bool {
+    from_param(pathname.strip_prefix('/').filter(|p| !p.is_empty())).is_some()
+}
+
+/// Bring section `id` to the top of the screen.
+pub fn scroll_to(id: &str, smooth: bool) {
+    let Some(el) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(id))
+    else {
+        return;
+    };
+    let opts = ScrollIntoViewOptions::new();
+    opts.set_behavior(if smooth {
+        ScrollBehavior::Smooth
+    } else {
+        ScrollBehavior::Instant
+    });
+    opts.set_block(ScrollLogicalPosition::Start);
+    el.scroll_into_view_with_scroll_into_view_options(&opts);
+}
+
+/// The last section whose top has scrolled past `SPY_LINE`.
+pub fn in_view() -> Option<&'static str> {
+    let window = web_sys::window()?;
+    let document = window.document()?;
+    let line = window.inner_height().ok()?.as_f64()? * SPY_LINE;
+
+    SECTIONS
+        .iter()
+        .copied()
+        .take_while(|id| {
+            document
+                .get_element_by_id(id)
+                .is_some_and(|el| el.get_bounding_client_rect().top() <= line)
+        })
+        .last()
+        .or(Some(SECTIONS[0]))
+}
@@ -0,0 +1,26 @@
+// The service worker scripts/prerender.sh writes next to index.html (see
+// scripts/sw.js), which makes the site work offline.
+
+use wasm_bindgen::JsValue;
+
+use crate::utils::site_data::SITE;
+
+/// Start the service worker, where there is one to start: browsers only
+/// expose them on https (and localhost), and only a build whose `SITE_URL`
+/// is where it's served from knows its address; `trunk serve` doesn't have
+/// one, and shouldn't cache anything anyway.
+pub fn register() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let navigator = window.navigator();
+    if !js_sys::Reflect::has(&navigator, &JsValue::from_str("serviceWorker")).unwrap_or(false) {
+        return;
+    }
+    let origin = window.location().origin().unwrap_or_default();
+    if origin.is_empty() || !SITE.url("/").starts_with(&format!("{origin}/")) {
+        return;
+    }
+    // it installs in the background; a failure only means no offline copy
+    let _ = navigator.service_worker().register(&SITE.url("/sw.js"));
+}
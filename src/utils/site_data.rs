@@ -0,0 +1,40 @@
+/// Site-wide settings from content/site.toml.
+#[derive(Clone, Copy)]
+pub struct Site {
+    /// Where the site is served from, GitHub Pages' `--public-url` prefix
+    /// included, without a trailing slash.
+    pub base_url: &'static str,
+    /// The path part of `base_url`, "" at the root of a host. The router
+    /// serves the pages under it.
+    pub base_path: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    /// Link preview for pages without one of their own.
+    pub og_image: &'static str,
+}
+
+impl Site {
+    /// Absolute URL of a site path like "/posts/x".
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// A site path like "/posts/x" as a link on the site itself.
+    pub fn href(&self, path: &str) -> String {
+        format!("{}{path}", self.base_path)
+    }
+
+    /// The site path a `location.pathname` is at, the other way round from
+    /// `href`.
+    pub fn route<'a>(&self, pathname: &'a str) -> &'a str {
+        match pathname.strip_prefix(self.base_path) {
+            Some("") => "/",
+            Some(path) if path.starts_with('/') => path,
+            _ => pathname,
+        }
+    }
+}
+
+// NOTE: `SITE` is generated by build/site.rs, with `base_url` overridden by
+// $SITE_URL when set.
+include!(concat!(env!("OUT_DIR"), "/site_data.rs"));
@@ -0,0 +1,110 @@
+//! How long the GPU spends on the clouds, where the browser will say.
+//! `EXT_disjoint_timer_query_webgl2` times the draw calls between `begin` and
+//! `end` without the CPU waiting on them; the result turns up a frame or two
+//! later. WebGL1, and browsers that don't expose the extension, get no timer
+//! and the quality controller goes by frame times alone.
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+
+use web_sys::{WebGl2RenderingContext as Gl, WebGlQuery};
+
+use crate::wasm::init::GlContext;
+
+const EXTENSION: &str = "EXT_disjoint_timer_query_webgl2";
+// the extension's, which web-sys has no constants for
+const TIME_ELAPSED_EXT: u32 = 0x88BF;
+const GPU_DISJOINT_EXT: u32 = 0x8FBB;
+/// Results waiting on the GPU at most; frames past that go untimed.
+const MAX_PENDING: usize = 4;
+
+pub struct GpuTimer {
+    gl: Gl,
+    /// Oldest first.
+    pending: RefCell<VecDeque<WebGlQuery>>,
+    running: Cell<bool>,
+}
+
+impl GpuTimer {
+    pub fn new(gl: &GlContext) -> Option<Self> {
+        let GlContext::WebGl2(gl) = gl else {
+            return None;
+        };
+        gl.get_extension(EXTENSION).ok().flatten()?;
+        Some(Self {
+            gl: gl.clone(),
+            pending: RefCell::default(),
+            running: Cell::new(false),
+        })
+    }
+
+    /// Start timing the draw calls that follow, unless too many results are
+    /// still outstanding.
+    pub fn begin(&self) {
+        if self.pending.borrow().len() >= MAX_PENDING {
+            return;
+        }
+        let Some(query) = self.gl.create_query() else {
+            return;
+        };
+        self.gl.begin_query(TIME_ELAPSED_EXT, &query);
+        self.pending.borrow_mut().push_back(query);
+        self.running.set(true);
+    }
+
+    pub fn end(&self) {
+        if self.running.replace(false) {
+            self.gl.end_query(TIME_ELAPSED_EXT);
+        }
+    }
+
+    /// Milliseconds each timed frame took on the GPU, oldest first, for the
+    /// frames whose results are in. Something like a power state change
+    /// makes the GPU report a disjoint event, which voids every result
+    /// still pending.
+    pub fn results(&self) -> Vec<f64> {
+        let mut pending = self.pending.borrow_mut();
+        let disjoint = self
+            .gl
+            .get_parameter(GPU_DISJOINT_EXT)
+            .ok()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if disjoint {
+            for query in pending.drain(..) {
+                self.gl.delete_query(Some(&query));
+            }
+            return Vec::new();
+        }
+
+        let mut out = Vec::new();
+        while let Some(query) = pending.front() {
+            let available = self
+                .gl
+                .get_query_parameter(query, Gl::QUERY_RESULT_AVAILABLE)
+                .as_bool()
+                .unwrap_or(false);
+            if !available {
+                break;
+            }
+            // nanoseconds
+            if let Some(ns) = self
+                .gl
+                .get_query_parameter(query, Gl::QUERY_RESULT)
+                .as_f64()
+            {
+                out.push(ns / 1e6);
+            }
+            self.gl.delete_query(pending.pop_front().as_ref());
+        }
+        out
+    }
+}
+
+impl Drop for GpuTimer {
+    fn drop(&mut self) {
+        for query in self.pending.get_mut().drain(..) {
+            self.gl.delete_query(Some(&query));
+        }
+    }
+}
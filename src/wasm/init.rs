@@ -1,19 +1,75 @@
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::WebGlBuffer;
+use web_sys::WebGlRenderingContext;
 use web_sys::WebGlUniformLocation;
 use web_sys::{HtmlCanvasElement, WebGl2RenderingContext, WebGlProgram, WebGlShader};
 
 // These are some helpers for rendering shaders using WebGL
 // AKA inner wiring thats never touched
 
+/// A WebGL2 context, or a WebGL1 one where that's all there is. The two share
+/// every call made here and their constants, so `WebGl2RenderingContext::*`
+/// serve both.
+pub enum GlContext {
+    WebGl2(WebGl2RenderingContext),
+    WebGl1(WebGlRenderingContext),
+}
+
+macro_rules! forward {
+    ($(fn $name:ident(&self $(, $arg:ident: $ty:ty)*) $(-> $ret:ty)?;)*) => {
+        impl GlContext {
+            $(pub fn $name(&self $(, $arg: $ty)*) $(-> $ret)? {
+                match self {
+                    GlContext::WebGl2(gl) => gl.$name($($arg),*),
+                    GlContext::WebGl1(gl) => gl.$name($($arg),*),
+                }
+            })*
+        }
+    };
+}
+
+forward! {
+    fn create_shader(&self, shader_type: u32) -> Option<WebGlShader>;
+    fn shader_source(&self, shader: &WebGlShader, source: &str);
+    fn compile_shader(&self, shader: &WebGlShader);
+    fn get_shader_parameter(&self, shader: &WebGlShader, pname: u32) -> JsValue;
+    fn get_shader_info_log(&self, shader: &WebGlShader) -> Option<String>;
+    fn create_program(&self) -> Option<WebGlProgram>;
+    fn attach_shader(&self, program: &WebGlProgram, shader: &WebGlShader);
+    fn link_program(&self, program: &WebGlProgram);
+    fn delete_shader(&self, shader: Option<&WebGlShader>);
+    fn delete_program(&self, program: Option<&WebGlProgram>);
+    fn get_program_parameter(&self, program: &WebGlProgram, pname: u32) -> JsValue;
+    fn get_program_info_log(&self, program: &WebGlProgram) -> Option<String>;
+    fn use_program(&self, program: Option<&WebGlProgram>);
+    fn get_attrib_location(&self, program: &WebGlProgram, name: &str) -> i32;
+    fn get_uniform_location(&self, program: &WebGlProgram, name: &str) -> Option<WebGlUniformLocation>;
+    fn create_buffer(&self) -> Option<WebGlBuffer>;
+    fn bind_buffer(&self, target: u32, buffer: Option<&WebGlBuffer>);
+    fn delete_buffer(&self, buffer: Option<&WebGlBuffer>);
+    fn buffer_data_with_array_buffer_view(&self, target: u32, data: &js_sys::Object, usage: u32);
+    fn vertex_attrib_pointer_with_i32(&self, index: u32, size: i32, ty: u32, normalized: bool, stride: i32, offset: i32);
+    fn enable_vertex_attrib_array(&self, index: u32);
+    fn enable(&self, cap: u32);
+    fn blend_func(&self, sfactor: u32, dfactor: u32);
+    fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32);
+    fn clear(&self, mask: u32);
+    fn viewport(&self, x: i32, y: i32, width: i32, height: i32);
+    fn uniform1f(&self, location: Option<&WebGlUniformLocation>, x: f32);
+    fn uniform2f(&self, location: Option<&WebGlUniformLocation>, x: f32, y: f32);
+    fn uniform3f(&self, location: Option<&WebGlUniformLocation>, x: f32, y: f32, z: f32);
+    fn uniform4f(&self, location: Option<&WebGlUniformLocation>, x: f32, y: f32, z: f32, w: f32);
+    fn draw_arrays(&self, mode: u32, first: i32, count: i32);
+}
+
 pub const FULLSCREEN_QUAD: [f32; 12] = [
     -1.0, -1.0, 1.0, -1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0, 1.0, 1.0,
 ];
 
 /// Compile a GLSL shader and return the handle or an error
 pub fn compile_shader(
-    gl: &WebGl2RenderingContext,
+    gl: &GlContext,
     shader_type: u32,
     source: &str,
 ) -> Result<WebGlShader, JsValue> {
@@ -37,7 +93,7 @@ pub fn compile_shader(
 
 /// Link a vertex and fragment shader into a program
 pub fn link_program(
-    gl: &WebGl2RenderingContext,
+    gl: &GlContext,
     vert_shader: &WebGlShader,
     frag_shader: &WebGlShader,
 ) -> Result<WebGlProgram, JsValue> {
@@ -74,45 +130,58 @@ pub fn get_canvas(canvas_id: &str) -> Result<HtmlCanvasElement, JsValue> {
     Ok(canvas)
 }
 
-pub fn get_webgl2_context(
-    canvas: &HtmlCanvasElement,
-    alpha: bool,
-) -> Result<WebGl2RenderingContext, JsValue> {
+fn context_options(alpha: bool) -> Result<JsValue, JsValue> {
     let attrs = js_sys::Object::new();
     js_sys::Reflect::set(&attrs, &"alpha".into(), &alpha.into())?;
-    js_sys::Reflect::set(&attrs, &"premultipliedAlpha".into(), &false.into())?;
+    js_sys::Reflect::set(&attrs, &"premultipliedAlpha".into(), &true.into())?;
+    Ok(attrs.into())
+}
 
+pub fn get_webgl2_context(canvas: &HtmlCanvasElement, alpha: bool) -> Result<GlContext, JsValue> {
     let gl = canvas
-        .get_context_with_context_options("webgl2", &attrs.into())?
+        .get_context_with_context_options("webgl2", &context_options(alpha)?)?
         .ok_or_else(|| JsValue::from_str("no WebGL2 context"))?
         .dyn_into::<WebGl2RenderingContext>()?;
 
-    Ok(gl)
+    Ok(GlContext::WebGl2(gl))
+}
+
+pub fn get_webgl1_context(canvas: &HtmlCanvasElement, alpha: bool) -> Result<GlContext, JsValue> {
+    let gl = canvas
+        .get_context_with_context_options("webgl", &context_options(alpha)?)?
+        .ok_or_else(|| JsValue::from_str("no WebGL context"))?
+        .dyn_into::<WebGlRenderingContext>()?;
+
+    Ok(GlContext::WebGl1(gl))
 }
 
 pub fn build_program(
-    gl: &WebGl2RenderingContext,
+    gl: &GlContext,
     vertex_src: &str,
     fragment_src: &str,
 ) -> Result<WebGlProgram, JsValue> {
     let vs = compile_shader(gl, WebGl2RenderingContext::VERTEX_SHADER, vertex_src)?;
     let fs = compile_shader(gl, WebGl2RenderingContext::FRAGMENT_SHADER, fragment_src)?;
-    let program = link_program(gl, &vs, &fs)?;
+    let linked = link_program(gl, &vs, &fs);
+    // a linked program keeps what it needs of them
+    gl.delete_shader(Some(&vs));
+    gl.delete_shader(Some(&fs));
 
-    Ok(program)
+    linked
 }
 
-pub fn setup_alpha_blending(gl: &WebGl2RenderingContext) {
+pub fn setup_alpha_blending(gl: &GlContext) {
     gl.enable(WebGl2RenderingContext::BLEND);
     gl.blend_func(
-        WebGl2RenderingContext::SRC_ALPHA,
+        // the cloud shader's output is premultiplied
+        WebGl2RenderingContext::ONE,
         WebGl2RenderingContext::ONE_MINUS_SRC_ALPHA,
     );
     gl.clear_color(0.0, 0.0, 0.0, 0.0);
 }
 
 pub fn uniform(
-    gl: &WebGl2RenderingContext,
+    gl: &GlContext,
     program: &WebGlProgram,
     name: &str,
 ) -> Result<WebGlUniformLocation, JsValue> {
@@ -121,7 +190,7 @@ pub fn uniform(
 }
 
 pub fn upload_array_buffer(
-    gl: &WebGl2RenderingContext,
+    gl: &GlContext,
     data: &[f32],
     usage: u32,
 ) -> Result<WebGlBuffer, JsValue> {
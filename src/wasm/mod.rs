@@ -1,2 +1,5 @@
 pub mod cloud_shader;
+pub mod gpu_timer;
 pub mod init;
+pub mod quality;
+pub mod sky;
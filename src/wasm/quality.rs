@@ -0,0 +1,272 @@
+//! Holds the clouds to a steady frame rate. The time between frames, and the
+//! GPU's time drawing the clouds where there's a `GpuTimer`, are averaged
+//! over short windows. A window of late frames steps quality down at once,
+//! if the clouds took more than their share of the frame; a display that
+//! simply refreshes slower than 60 Hz, or a page busy with something else,
+//! leaves it be. Without GPU times that can only be found out by trying: a
+//! step down that doesn't bring the frames closer together is taken back,
+//! and frames that far apart aren't late from then on. It takes a run of
+//! fast windows to step back up. A step up that's followed by a step down
+//! soon after makes the next step up wait twice as long, so a machine that
+//! sits on the edge of two levels settles instead of flickering.
+
+/// What a quality level draws.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quality {
+    pub name: &'static str,
+    /// Times each view's `CloudOptions::render_scale`.
+    pub render_scale: f32,
+    /// The shader's march step; bigger is fewer samples.
+    pub step: f32,
+    /// Sun samples per shadow, 1 to 8.
+    pub shadow_samples: f32,
+    /// The share of the background's clouds drawn.
+    pub bg_clouds: f32,
+}
+
+/// Cheapest first; the last is the clouds as designed.
+pub const LEVELS: [Quality; 4] = [
+    Quality {
+        name: "low",
+        render_scale: 0.6,
+        step: 0.07,
+        shadow_samples: 3.0,
+        bg_clouds: 0.5,
+    },
+    Quality {
+        name: "medium",
+        render_scale: 0.75,
+        step: 0.055,
+        shadow_samples: 4.0,
+        bg_clouds: 0.65,
+    },
+    Quality {
+        name: "high",
+        render_scale: 0.9,
+        step: 0.045,
+        shadow_samples: 6.0,
+        bg_clouds: 0.85,
+    },
+    Quality {
+        name: "full",
+        render_scale: 1.0,
+        step: 0.035,
+        shadow_samples: 8.0,
+        bg_clouds: 1.0,
+    },
+];
+
+const TARGET_MS: f64 = 1000.0 / 60.0;
+/// Frames averaged at a time, about half a second's worth.
+const WINDOW: u32 = 30;
+/// Frames averaging this much further apart than the page manages are late.
+const SLOW: f64 = 1.25;
+/// Frames averaging closer together than this, relative to what the page
+/// manages, count towards a step up.
+const FAST: f64 = 1.1;
+/// How much closer together frames have to come for an untimed step down
+/// to stay.
+const SPED_UP: f64 = 1.1;
+/// How long drawing the clouds may take in a frame; late frames only step
+/// down when the clouds took longer. Drawing in under half of it counts
+/// towards a step up too, however far apart the frames are.
+const BUDGET_MS: f64 = TARGET_MS * 0.5;
+const FAST_WINDOWS: u32 = 4;
+const MAX_FAST_WINDOWS: u32 = 64;
+/// How long a step up is on trial: a step down within this many windows
+/// counts against it.
+const TRIAL_WINDOWS: u32 = 8;
+
+pub struct Controller {
+    level: usize,
+    /// The time between frames the page manages with the clouds out of the
+    /// way: 60 Hz, until an untimed step down shows the display is slower.
+    cadence_ms: f64,
+    sum_ms: f64,
+    frames: u32,
+    sum_draw_ms: f64,
+    drawn: u32,
+    /// The average of the window that stepped down, while an untimed step
+    /// down waits to be proven.
+    down_from: Option<f64>,
+    fast_windows: u32,
+    /// Fast windows in a row the next step up needs.
+    fast_needed: u32,
+    /// Windows since the last step up, while it's on trial.
+    since_up: Option<u32>,
+}
+
+impl Default for Controller {
+    fn default() -> Self {
+        Self {
+            level: LEVELS.len() - 1,
+            cadence_ms: TARGET_MS,
+            sum_ms: 0.0,
+            frames: 0,
+            sum_draw_ms: 0.0,
+            drawn: 0,
+            down_from: None,
+            fast_windows: 0,
+            fast_needed: FAST_WINDOWS,
+            since_up: None,
+        }
+    }
+}
+
+impl Controller {
+    pub fn quality(&self) -> Quality {
+        LEVELS[self.level]
+    }
+
+    /// Count the GPU taking `ms` over the clouds of a frame, for the
+    /// window under way.
+    pub fn record_draw(&mut self, ms: f64) {
+        self.sum_draw_ms += ms;
+        self.drawn += 1;
+    }
+
+    /// Count a frame that came `ms` after the one before. Returns the new
+    /// quality if this changed it.
+    pub fn record(&mut self, ms: f64) -> Option<Quality> {
+        self.sum_ms += ms;
+        self.frames += 1;
+        if self.frames < WINDOW {
+            return None;
+        }
+        let average = self.sum_ms / f64::from(self.frames);
+        let average_draw = (self.drawn > 0).then(|| self.sum_draw_ms / f64::from(self.drawn));
+        self.sum_ms = 0.0;
+        self.frames = 0;
+        self.sum_draw_ms = 0.0;
+        self.drawn = 0;
+        self.since_up = self.since_up.map(|w| w + 1).filter(|&w| w < TRIAL_WINDOWS);
+
+        if let Some(before) = self.down_from.take()
+            && average_draw.is_none()
+            && average * SPED_UP > before
+        {
+            // the clouds weren't what held the frames back
+            self.cadence_ms = self.cadence_ms.max(average);
+            self.level += 1;
+            return Some(self.quality());
+        }
+
+        let late = average > self.cadence_ms * SLOW;
+        if late && average_draw.is_none_or(|draw| draw > BUDGET_MS) && self.level > 0 {
+            if self.since_up.take().is_some() {
+                self.fast_needed = (self.fast_needed * 2).min(MAX_FAST_WINDOWS);
+            }
+            self.fast_windows = 0;
+            self.down_from = average_draw.is_none().then_some(average);
+            self.level -= 1;
+            return Some(self.quality());
+        }
+
+        if average < self.cadence_ms * FAST
+            || average_draw.is_some_and(|draw| draw < BUDGET_MS / 2.0)
+        {
+            self.fast_windows += 1;
+        } else {
+            self.fast_windows = 0;
+        }
+        if self.fast_windows >= self.fast_needed && self.level + 1 < LEVELS.len() {
+            self.fast_windows = 0;
+            self.since_up = Some(0);
+            self.level += 1;
+            return Some(self.quality());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Drawing that takes up the whole budget, so only the frame times count.
+    const DRAW_MS: f64 = BUDGET_MS * 1.5;
+
+    /// Feed `windows` windows of frames `ms` apart; the levels stepped to.
+    fn run(controller: &mut Controller, ms: f64, windows: u32) -> Vec<&'static str> {
+        run_drawing(controller, ms, Some(DRAW_MS), windows)
+    }
+
+    /// As `run`, without a GPU timer.
+    fn run_untimed(controller: &mut Controller, ms: f64, windows: u32) -> Vec<&'static str> {
+        run_drawing(controller, ms, None, windows)
+    }
+
+    /// As `run`, with the GPU taking `draw_ms` over the clouds a frame.
+    fn run_drawing(
+        controller: &mut Controller,
+        ms: f64,
+        draw_ms: Option<f64>,
+        windows: u32,
+    ) -> Vec<&'static str> {
+        (0..windows * WINDOW)
+            .filter_map(|_| {
+                if let Some(draw_ms) = draw_ms {
+                    controller.record_draw(draw_ms);
+                }
+                controller.record(ms)
+            })
+            .map(|q| q.name)
+            .collect()
+    }
+
+    #[test]
+    fn steps_down_one_level_per_slow_window() {
+        let mut controller = Controller::default();
+        assert_eq!(run(&mut controller, 30.0, 2), ["high", "medium"]);
+        assert_eq!(run(&mut controller, 30.0, 3), ["low"]);
+    }
+
+    #[test]
+    fn steps_up_only_after_a_run_of_fast_windows() {
+        let mut controller = Controller::default();
+        run(&mut controller, 30.0, 1);
+        assert!(run(&mut controller, TARGET_MS, FAST_WINDOWS - 1).is_empty());
+        assert_eq!(run(&mut controller, TARGET_MS, 1), ["full"]);
+    }
+
+    #[test]
+    fn frames_between_the_thresholds_hold_the_level() {
+        let mut controller = Controller::default();
+        run(&mut controller, 30.0, 1);
+        assert!(run(&mut controller, TARGET_MS * 1.2, 100).is_empty());
+    }
+
+    #[test]
+    fn a_failed_step_up_makes_the_next_wait_longer() {
+        let mut controller = Controller::default();
+        run(&mut controller, 30.0, 1);
+        assert_eq!(run(&mut controller, TARGET_MS, FAST_WINDOWS), ["full"]);
+        assert_eq!(run(&mut controller, 30.0, 1), ["high"]);
+        assert!(run(&mut controller, TARGET_MS, FAST_WINDOWS * 2 - 1).is_empty());
+        assert_eq!(run(&mut controller, TARGET_MS, 1), ["full"]);
+    }
+
+    #[test]
+    fn a_30_hz_display_holds_the_level_while_the_clouds_fit() {
+        let mut controller = Controller::default();
+        let cadence = 1000.0 / 30.0;
+        assert!(run_drawing(&mut controller, cadence, Some(BUDGET_MS * 0.8), 100).is_empty());
+        assert_eq!(controller.quality().name, "full");
+    }
+
+    #[test]
+    fn untimed_a_30_hz_display_takes_back_its_one_step_down() {
+        let mut controller = Controller::default();
+        let cadence = 1000.0 / 30.0;
+        assert_eq!(run_untimed(&mut controller, cadence, 2), ["high", "full"]);
+        assert!(run_untimed(&mut controller, cadence, 100).is_empty());
+    }
+
+    #[test]
+    fn untimed_a_step_down_that_speeds_frames_up_stays() {
+        let mut controller = Controller::default();
+        assert_eq!(run_untimed(&mut controller, 30.0, 1), ["high"]);
+        assert!(run_untimed(&mut controller, 20.0, 10).is_empty());
+        assert_eq!(run_untimed(&mut controller, 30.0, 1), ["medium"]);
+    }
+}
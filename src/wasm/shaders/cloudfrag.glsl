@@ -2,7 +2,13 @@
 // Bright, soft, realistic-white cloud with gentle sky ambient.
 // Mouse interactivity (dent + subtle sheen) is controlled by u_mouse_mix (hero=1, bg=0).
 
+// GLSL ES 1.00, so WebGL1 runs it as well as WebGL2; WebGL1 doesn't
+// promise highp in fragment shaders
+#ifdef GL_FRAGMENT_PRECISION_HIGH
 precision highp float;
+#else
+precision mediump float;
+#endif
 
 varying vec2 v_uv;
 
@@ -16,8 +22,19 @@ uniform float u_seed;
 // 1.0 = enable mouse interactivity (hero cloud)
 uniform float u_mouse_mix;
 
+// how much of the cloud shows through, on top of its own density
+uniform float u_opacity;
+
+// quality, set by the sky's controller: march step, and sun samples per
+// shadow (1 to 8)
+uniform float u_step;
+uniform float u_shadow_samples;
+
 // ---- tweak knobs ----
+// the step and shadow reach the look is tuned to; other steps and sample
+// counts are scaled to match
 const float STEP = 0.035;
+const float SHADOW_REACH = 0.6;
 const float TMAX = 5.0;
 
 // mouse blob size (bigger = larger interaction area)
@@ -141,7 +158,7 @@ void main() {
   // dither to reduce banding
   float t = 0.0;
   float dither = hash(dot(gl_FragCoord.xy, vec2(12.9898, 78.233)) + u_seed * 13.7);
-  t += (dither - 0.5) * STEP * 0.9;
+  t += (dither - 0.5) * u_step * 0.9;
 
   vec3 sum = vec3(0.0);
   float trans = 1.0;
@@ -182,10 +199,12 @@ void main() {
 
       float shadow = 1.0;
       float lt = 0.04;
+      float lstep = SHADOW_REACH / u_shadow_samples;
       for (int s = 0; s < 8; s++) {
+        if (float(s) >= u_shadow_samples) break;
         float ld = densityAt(p_def + sunDir * lt, time);
-        shadow *= exp(-ld * 1.35);
-        lt += 0.075;
+        shadow *= exp(-ld * 1.35 * lstep / (SHADOW_REACH / 8.0));
+        lt += lstep;
       }
       shadow = clamp(shadow, 0.35, 1.0);
 
@@ -201,18 +220,23 @@ void main() {
       lit += influence * SHEEN_STRENGTH * vec3(0.25, 0.35, 0.55);
 
       // opacity from density (NO alpha killing -> avoids tunnel/hole)
-      float alpha = 1.0 - exp(-dens * 2.8);
+      float alpha = 1.0 - exp(-dens * 2.8 * u_step / STEP);
 
       sum += trans * alpha * lit;
       trans *= (1.0 - alpha);
     }
 
-    t += STEP;
+    t += u_step;
   }
 
   float outA = 1.0 - trans;
   vec3 outCol = (outA > 1e-4) ? (sum / outA) : vec3(0.0);
 
   outCol = pow(clamp(outCol, 0.0, 1.0), vec3(1.0 / 2.2));
-  gl_FragColor = vec4(outCol, outA);
+
+  // Premultiplied, so clouds sharing a view blend over each other. The look
+  // is tuned to what a canvas of its own made of this: color outCol * outA
+  // at alpha outA * outA.
+  float a = outA * outA * u_opacity;
+  gl_FragColor = vec4(outCol * outA * a, a);
 }
@@ -1,7 +1,10 @@
 attribute vec2 position;
+// where the cloud goes in its view, in clip space: x and y of the
+// bottom-left corner, then width and height
+uniform vec4 u_rect;
 varying vec2 v_uv;
 
 void main() {
     v_uv = position * 0.5 + 0.5;
-    gl_Position = vec4(position, 0.0, 1.0);
-}
\ No newline at end of file
+    gl_Position = vec4(u_rect.xy + v_uv * u_rect.zw, 0.0, 1.0);
+}
@@ -0,0 +1,550 @@
+//! The one WebGL context every cloud on the page is drawn with.
+//!
+//! Browsers cap how many WebGL contexts a page may hold, and each one would
+//! compile its own copy of the raymarcher, so there is a single context on a
+//! canvas that is never put in the page. A canvas that shows clouds is a
+//! *view*: a plain 2D canvas whose clouds the sky draws and copies over once
+//! a frame, all from one animation loop. The sky is built for the first view
+//! and released with the last one.
+//!
+//! Nothing is drawn for a view that's scrolled out of sight, and nothing at
+//! all in a hidden tab. Each view keeps its own clock, which only runs while
+//! it's drawn, so clouds carry on from where they were rather than jump.
+//!
+//! How fine the clouds are drawn is up to `quality::Controller`, going by
+//! the time between frames and, where a `GpuTimer` can tell, how long the
+//! GPU took over the clouds. The level in use is on `<html>` as
+//! `data-cloud-quality`.
+//!
+//! Where there's no WebGL2, or it won't build the shader, the sky is WebGL1;
+//! failing that too there's no sky, and `tier()` says so for components to
+//! show something else.
+
+use std::cell::{Cell, OnceCell, RefCell};
+use std::rc::{Rc, Weak};
+
+use leptos::prelude::*;
+use wasm_bindgen::{JsCast, prelude::*};
+use web_sys::{
+    CanvasRenderingContext2d, Document, Element, HtmlCanvasElement, IntersectionObserver,
+    IntersectionObserverEntry, WebGl2RenderingContext as Gl, WebGlBuffer, WebGlProgram,
+    WebGlUniformLocation, Window,
+};
+
+use crate::wasm::cloud_shader::{
+    CLOUD_FRAGMENT_SHADER, CLOUD_VERTEX_SHADER, CloudOptions, global_mouse, scroll_progress,
+};
+use crate::wasm::gpu_timer::GpuTimer;
+use crate::wasm::init::{
+    FULLSCREEN_QUAD, GlContext, build_program, get_webgl1_context, get_webgl2_context,
+    setup_alpha_blending, uniform, upload_array_buffer,
+};
+use crate::wasm::quality::{Controller, Quality};
+
+/// The most a view's clock moves in one frame, so a stall doesn't skip the
+/// clouds ahead either.
+const MAX_FRAME_TIME: f64 = 0.1;
+
+/// How clouds are drawn here, best first.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CloudTier {
+    WebGl2,
+    WebGl1,
+    /// No WebGL that runs the shader.
+    Static,
+}
+
+/// One cloud in a view, in the view's CSS pixels.
+#[derive(Clone, Copy)]
+pub struct Placement {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub opacity: f32,
+    pub seed: f32,
+    /// Added to the clock, so clouds don't billow in step.
+    pub time_offset: f32,
+}
+
+/// What a view's layout is asked with, once a frame.
+pub struct Frame {
+    /// Seconds the view has been drawn for.
+    pub time: f32,
+    /// The view's size in CSS pixels.
+    pub width: f32,
+    pub height: f32,
+    /// The view's options.
+    pub opts: CloudOptions,
+    /// The level the sky is drawing at.
+    pub quality: Quality,
+}
+
+/// Where a view's clouds are this frame; later ones are drawn on top.
+pub type Layout = Box<dyn FnMut(&Frame) -> Vec<Placement>>;
+
+/// A view from `add_view`. It's `Copy`, so cleanups and closures take it as
+/// is; stopping a view that's already gone does nothing.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CloudHandle(u64);
+
+struct View {
+    id: u64,
+    /// As last reported by the sky's IntersectionObserver.
+    on_screen: bool,
+    time: f32,
+    canvas: HtmlCanvasElement,
+    ctx: CanvasRenderingContext2d,
+    opts: CloudOptions,
+    layout: Layout,
+}
+
+struct Uniforms {
+    rect: WebGlUniformLocation,
+    time: WebGlUniformLocation,
+    resolution: WebGlUniformLocation,
+    opacity: WebGlUniformLocation,
+    step: WebGlUniformLocation,
+    shadow_samples: WebGlUniformLocation,
+    mouse: Option<WebGlUniformLocation>,
+    sun_dir: Option<WebGlUniformLocation>,
+    scroll: Option<WebGlUniformLocation>,
+    seed: Option<WebGlUniformLocation>,
+    mouse_mix: Option<WebGlUniformLocation>,
+}
+
+struct Sky {
+    canvas: HtmlCanvasElement,
+    gl: GlContext,
+    // bound once, and deleted when the sky is dropped
+    program: WebGlProgram,
+    quad: WebGlBuffer,
+    uniforms: Uniforms,
+    timer: Option<GpuTimer>,
+    views: RefCell<Vec<View>>,
+    /// The animation frame asked for, if any.
+    raf: Cell<Option<i32>>,
+    /// When the last frame was, while frames follow one another.
+    last_frame: Cell<Option<f64>>,
+    frame: Closure<dyn FnMut(f64)>,
+    document: Document,
+    on_visibility: Closure<dyn FnMut()>,
+    observer: IntersectionObserver,
+    _on_intersect: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+thread_local! {
+    static SKY: RefCell<Option<Rc<Sky>>> = const { RefCell::new(None) };
+    // probed the first time anything asks; reference counted rather than
+    // owned by whichever component asked first, which may go away
+    static TIER: OnceCell<ArcRwSignal<CloudTier>> = const { OnceCell::new() };
+    static NEXT_VIEW: Cell<u64> = const { Cell::new(0) };
+    // outlives any one sky, so a rebuilt sky starts where the last one was
+    static QUALITY: RefCell<Controller> = RefCell::new(Controller::default());
+}
+
+/// The tier clouds are drawn with, probing for WebGL the first time.
+pub fn tier() -> Signal<CloudTier> {
+    tier_signal().read_only().into()
+}
+
+fn tier_signal() -> ArcRwSignal<CloudTier> {
+    TIER.with(|tier| tier.get_or_init(|| ArcRwSignal::new(probe())).clone())
+}
+
+/// WebGL2, else WebGL1, else nothing. Runs once, so each tier that fails is
+/// reported once; the sky it builds is kept for the first view.
+fn probe() -> CloudTier {
+    for tier in [CloudTier::WebGl2, CloudTier::WebGl1] {
+        match Sky::new(tier) {
+            Ok(sky) => {
+                SKY.set(Some(sky));
+                return tier;
+            }
+            Err(e) => report(&format!("clouds: no {tier:?}"), &e),
+        }
+    }
+    CloudTier::Static
+}
+
+/// The sky, built again at the probed tier if the last view took it away.
+fn sky() -> Result<Rc<Sky>, JsValue> {
+    let tier = tier_signal();
+    if let Some(sky) = current() {
+        return Ok(sky);
+    }
+    if tier.get_untracked() == CloudTier::Static {
+        return Err(JsValue::from_str("clouds: no WebGL"));
+    }
+    let sky = Sky::new(tier.get_untracked()).inspect_err(|e| {
+        report("clouds: can't set up WebGL again", e);
+        tier.set(CloudTier::Static);
+    })?;
+    SKY.set(Some(sky.clone()));
+    Ok(sky)
+}
+
+fn current() -> Option<Rc<Sky>> {
+    SKY.with_borrow(|sky| sky.clone())
+}
+
+fn report(what: &str, error: &JsValue) {
+    web_sys::console::warn_2(&JsValue::from_str(what), error);
+}
+
+/// Draw clouds into `canvas` from now until it leaves the page. Failures are
+/// logged as well as returned.
+pub fn add_view(
+    canvas: HtmlCanvasElement,
+    opts: CloudOptions,
+    layout: Layout,
+) -> Result<CloudHandle, JsValue> {
+    let sky = sky()?;
+    let ctx = canvas
+        .get_context("2d")
+        .and_then(|ctx| {
+            ctx.ok_or_else(|| JsValue::from_str("no 2D context"))?
+                .dyn_into::<CanvasRenderingContext2d>()
+                .map_err(JsValue::from)
+        })
+        .inspect_err(|e| report("clouds: can't draw into the canvas", e))?;
+    let id = NEXT_VIEW.replace(NEXT_VIEW.get() + 1);
+    sky.observer.observe(&canvas);
+    sky.views.borrow_mut().push(View {
+        id,
+        on_screen: true,
+        time: 0.0,
+        canvas,
+        ctx,
+        opts,
+        layout,
+    });
+    sky.schedule();
+    Ok(CloudHandle(id))
+}
+
+impl CloudHandle {
+    /// Drop the view. The last one to go takes the sky with it, releasing
+    /// the program, the buffer and the animation frame.
+    pub fn stop(self) {
+        let Some(sky) = current() else {
+            return;
+        };
+        let mut views = sky.views.borrow_mut();
+        views.retain(|view| {
+            let keep = view.id != self.0;
+            if !keep {
+                sky.observer.unobserve(&view.canvas);
+            }
+            keep
+        });
+        if views.is_empty() {
+            SKY.set(None);
+        }
+    }
+}
+
+impl Sky {
+    fn new(tier: CloudTier) -> Result<Rc<Self>, JsValue> {
+        let document = web_sys::window()
+            .and_then(|w| w.document())
+            .ok_or_else(|| JsValue::from_str("no document"))?;
+        let canvas = document
+            .create_element("canvas")?
+            .dyn_into::<HtmlCanvasElement>()?;
+
+        let gl = match tier {
+            CloudTier::WebGl1 => get_webgl1_context(&canvas, true)?,
+            _ => get_webgl2_context(&canvas, true)?,
+        };
+        setup_alpha_blending(&gl);
+
+        let program = build_program(&gl, CLOUD_VERTEX_SHADER, CLOUD_FRAGMENT_SHADER)?;
+        gl.use_program(Some(&program));
+
+        let quad = upload_array_buffer(&gl, &FULLSCREEN_QUAD, Gl::STATIC_DRAW)?;
+        let position_attr = gl.get_attrib_location(&program, "position") as u32;
+        gl.vertex_attrib_pointer_with_i32(position_attr, 2, Gl::FLOAT, false, 0, 0);
+        gl.enable_vertex_attrib_array(position_attr);
+
+        let uniforms = Uniforms {
+            rect: uniform(&gl, &program, "u_rect")?,
+            time: uniform(&gl, &program, "u_time")?,
+            resolution: uniform(&gl, &program, "u_resolution")?,
+            opacity: uniform(&gl, &program, "u_opacity")?,
+            step: uniform(&gl, &program, "u_step")?,
+            shadow_samples: uniform(&gl, &program, "u_shadow_samples")?,
+            mouse: gl.get_uniform_location(&program, "u_mouse"),
+            sun_dir: gl.get_uniform_location(&program, "u_sun_dir"),
+            scroll: gl.get_uniform_location(&program, "u_scroll"),
+            seed: gl.get_uniform_location(&program, "u_seed"),
+            mouse_mix: gl.get_uniform_location(&program, "u_mouse_mix"),
+        };
+
+        // these go through SKY, which this sky is in by the time they fire
+        let on_intersect = Closure::<dyn FnMut(js_sys::Array)>::new(|entries: js_sys::Array| {
+            if let Some(sky) = current() {
+                sky.intersected(entries);
+            }
+        });
+        let observer = IntersectionObserver::new(on_intersect.as_ref().unchecked_ref())?;
+        let on_visibility = Closure::<dyn FnMut()>::new(|| {
+            if let Some(sky) = current() {
+                sky.visibility_changed();
+            }
+        });
+        document.add_event_listener_with_callback(
+            "visibilitychange",
+            on_visibility.as_ref().unchecked_ref(),
+        )?;
+
+        let timer = GpuTimer::new(&gl);
+        let sky = Rc::new_cyclic(|sky: &Weak<Sky>| {
+            let sky = sky.clone();
+            Self {
+                canvas,
+                gl,
+                program,
+                quad,
+                uniforms,
+                timer,
+                views: RefCell::new(Vec::new()),
+                raf: Cell::new(None),
+                last_frame: Cell::new(None),
+                frame: Closure::new(move |ms: f64| {
+                    if let Some(sky) = sky.upgrade() {
+                        sky.frame(ms);
+                    }
+                }),
+                document,
+                on_visibility,
+                observer,
+                _on_intersect: on_intersect,
+            }
+        });
+        sky.show_quality(QUALITY.with_borrow(Controller::quality));
+        Ok(sky)
+    }
+
+    /// Ask for a frame, unless one is on its way, the tab is hidden or no
+    /// view is to be drawn.
+    fn schedule(&self) {
+        if self.raf.get().is_some()
+            || self.document.hidden()
+            || !self.views.borrow().iter().any(|view| view.on_screen)
+        {
+            return;
+        }
+        if let Some(window) = web_sys::window() {
+            self.raf.set(
+                window
+                    .request_animation_frame(self.frame.as_ref().unchecked_ref())
+                    .ok(),
+            );
+        }
+    }
+
+    /// Drop the frame asked for; the next one starts the clocks afresh.
+    fn cancel(&self) {
+        if let (Some(raf), Some(window)) = (self.raf.take(), web_sys::window()) {
+            let _ = window.cancel_animation_frame(raf);
+        }
+        self.last_frame.set(None);
+    }
+
+    fn visibility_changed(&self) {
+        if self.document.hidden() {
+            self.cancel();
+        } else {
+            self.schedule();
+        }
+    }
+
+    fn intersected(&self, entries: js_sys::Array) {
+        for entry in entries.iter() {
+            let entry = entry.unchecked_into::<IntersectionObserverEntry>();
+            let target = entry.target();
+            if let Some(view) = self
+                .views
+                .borrow_mut()
+                .iter_mut()
+                .find(|view| AsRef::<Element>::as_ref(&view.canvas) == &target)
+            {
+                view.on_screen = entry.is_intersecting();
+            }
+        }
+        self.schedule();
+    }
+
+    fn frame(&self, ms: f64) {
+        self.raf.set(None);
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        // nothing for the first frame after a pause
+        let since_last = self.last_frame.replace(Some(ms)).map(|last| ms - last);
+        let dt = since_last.map_or(0.0, |ms| (ms / 1000.0).clamp(0.0, MAX_FRAME_TIME)) as f32;
+
+        let quality = QUALITY.with_borrow(Controller::quality);
+        self.gl.uniform1f(Some(&self.uniforms.step), quality.step);
+        self.gl
+            .uniform1f(Some(&self.uniforms.shadow_samples), quality.shadow_samples);
+
+        if let Some(timer) = &self.timer {
+            timer.begin();
+        }
+        // a view whose canvas left the page without stop() goes too
+        self.views.borrow_mut().retain_mut(|view| {
+            if !view.canvas.is_connected() {
+                self.observer.unobserve(&view.canvas);
+                return false;
+            }
+            if view.on_screen {
+                view.time += dt;
+                self.draw(&window, view, quality);
+            }
+            true
+        });
+
+        let changed = QUALITY.with_borrow_mut(|controller| {
+            if let Some(timer) = &self.timer {
+                timer.end();
+                for ms in timer.results() {
+                    controller.record_draw(ms);
+                }
+            }
+            controller.record(since_last?)
+        });
+        if let Some(quality) = changed {
+            self.show_quality(quality);
+        }
+
+        self.schedule();
+        if self.raf.get().is_none() {
+            self.last_frame.set(None);
+        }
+    }
+
+    fn show_quality(&self, quality: Quality) {
+        if let Some(html) = self.document.document_element() {
+            let _ = html.set_attribute("data-cloud-quality", quality.name);
+        }
+    }
+
+    fn draw(&self, window: &Window, view: &mut View, quality: Quality) {
+        let gl = &self.gl;
+        let u = &self.uniforms;
+        let opts = view.opts;
+
+        let dpr = (window.device_pixel_ratio() as f32).min(opts.dpr_cap);
+        let scale = dpr * opts.render_scale * quality.render_scale;
+
+        let css_w = view.canvas.client_width().max(1) as f32;
+        let css_h = view.canvas.client_height().max(1) as f32;
+
+        let pixel_w = ((css_w * scale).round() as u32).max(1);
+        let pixel_h = ((css_h * scale).round() as u32).max(1);
+
+        if view.canvas.width() != pixel_w {
+            view.canvas.set_width(pixel_w);
+        }
+        if view.canvas.height() != pixel_h {
+            view.canvas.set_height(pixel_h);
+        }
+        // only ever grows, so views of different sizes don't reallocate it
+        // every frame; each view uses the bottom-left corner
+        if self.canvas.width() < pixel_w {
+            self.canvas.set_width(pixel_w);
+        }
+        if self.canvas.height() < pixel_h {
+            self.canvas.set_height(pixel_h);
+        }
+
+        gl.viewport(0, 0, pixel_w as i32, pixel_h as i32);
+        gl.clear(Gl::COLOR_BUFFER_BIT);
+
+        if let (Some(loc), Some(sun)) = (&u.sun_dir, opts.default_sun) {
+            gl.uniform3f(Some(loc), sun[0], sun[1], sun[2]);
+        }
+        if let Some(loc) = &u.mouse_mix {
+            gl.uniform1f(Some(loc), opts.mouse_mix);
+        }
+        if let (true, Some(loc)) = (opts.use_scroll, &u.scroll) {
+            gl.uniform1f(Some(loc), scroll_progress());
+        }
+
+        // window coords -> view-local
+        let (mouse_x, mouse_y) = *global_mouse().borrow();
+        let bounds = view.canvas.get_bounding_client_rect();
+        let mouse_x = mouse_x - bounds.left() as f32;
+        let mouse_y = mouse_y - bounds.top() as f32;
+
+        let time = view.time;
+        let frame = Frame {
+            time,
+            width: css_w,
+            height: css_h,
+            opts,
+            quality,
+        };
+        for cloud in (view.layout)(&frame) {
+            gl.uniform4f(
+                Some(&u.rect),
+                cloud.x / css_w * 2.0 - 1.0,
+                1.0 - (cloud.y + cloud.height) / css_h * 2.0,
+                cloud.width / css_w * 2.0,
+                cloud.height / css_h * 2.0,
+            );
+            gl.uniform1f(Some(&u.time), time + cloud.time_offset);
+            gl.uniform2f(
+                Some(&u.resolution),
+                cloud.width * scale,
+                cloud.height * scale,
+            );
+            gl.uniform1f(Some(&u.opacity), cloud.opacity);
+            if let Some(loc) = &u.seed {
+                gl.uniform1f(Some(loc), cloud.seed);
+            }
+            // cloud-local, in the cloud's pixels
+            if let Some(loc) = &u.mouse {
+                gl.uniform2f(
+                    Some(loc),
+                    (mouse_x - cloud.x) * scale,
+                    (mouse_y - cloud.y) * scale,
+                );
+            }
+            gl.draw_arrays(Gl::TRIANGLES, 0, 6);
+        }
+
+        // GL rows run bottom-up: the viewport is the last `pixel_h` rows of
+        // the sky's canvas as an image
+        let (w, h) = (f64::from(pixel_w), f64::from(pixel_h));
+        view.ctx.clear_rect(0.0, 0.0, w, h);
+        let _ = view
+            .ctx
+            .draw_image_with_html_canvas_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+                &self.canvas,
+                0.0,
+                f64::from(self.canvas.height() - pixel_h),
+                w,
+                h,
+                0.0,
+                0.0,
+                w,
+                h,
+            );
+    }
+}
+
+impl Drop for Sky {
+    fn drop(&mut self) {
+        self.cancel();
+        self.observer.disconnect();
+        let _ = self.document.remove_event_listener_with_callback(
+            "visibilitychange",
+            self.on_visibility.as_ref().unchecked_ref(),
+        );
+        self.gl.delete_buffer(Some(&self.quad));
+        self.gl.delete_program(Some(&self.program));
+    }
+}